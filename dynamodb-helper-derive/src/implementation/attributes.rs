@@ -0,0 +1,372 @@
+use crate::{
+    attribute_name_constant, get_attribute_name, ALL_RENAME_RULES, ALL_TIMESTAMP_ENCODINGS, ALL_TYPE_OVERRIDES, AS_STRING_ATTRIBUTE_NAME,
+    CONTENT_ATTRIBUTE_NAME, DEFAULT_ATTRIBUTE_NAME, DEFAULT_CONTENT_ATTRIBUTE, DEFAULT_TAG_ATTRIBUTE,
+    DENY_UNKNOWN_ATTRIBUTES_ATTRIBUTE_NAME, DYNAMO_ATTRIBUTE_NAME, EXTRA_ATTRIBUTE_NAME, FLATTEN_ATTRIBUTE_NAME, JSON_ATTRIBUTE_NAME,
+    LIST_ATTRIBUTE_NAME, NESTED_ATTRIBUTE_NAME, RENAME_ALL_ATTRIBUTE_NAME, RENAME_ATTRIBUTE_NAME, SCHEMA_CONSTANT_NAME,
+    SERDE_ATTRIBUTE_NAME, SKIP_ATTRIBUTE_NAME, SKIP_SERIALIZING_IF_ATTRIBUTE_NAME, STORE_NULL_ATTRIBUTE_NAME, TAG_ATTRIBUTE_NAME,
+    TIMESTAMP_ATTRIBUTE_NAME, TRANSPARENT_ATTRIBUTE_NAME, TYPE_ATTRIBUTE_NAME, WITH_ATTRIBUTE_NAME,
+};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::Comma;
+use syn::{Attribute, Error, Field, LitStr, Path, Token};
+
+// options passed in with `#[dynamo(...)]` on the struct itself
+#[derive(Debug, Default)]
+pub struct StructAttributes {
+    pub nested: bool,
+    pub transparent: bool,
+    // save a None as a DynamoDB null for every optional field
+    pub store_null: bool,
+    // fill in every missing attribute with the default of its field type
+    pub default: bool,
+    pub rename_all: Option<RenameRule>,
+    // fail to parse an item with attributes that do not belong to any field
+    pub deny_unknown_attributes: bool,
+}
+
+// naming convention for the attributes of a struct, fields are expected to be in snake case
+#[derive(Debug, Clone, Copy)]
+pub enum RenameRule {
+    Camel,
+    Pascal,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    pub fn apply(&self, field_name: &str) -> String {
+        match self {
+            RenameRule::Camel => {
+                let pascal_case = RenameRule::Pascal.apply(field_name);
+                let mut chars = pascal_case.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            }
+            RenameRule::Pascal => field_name
+                .split('_')
+                .map(|part| {
+                    let mut chars = part.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                        .unwrap_or_default()
+                })
+                .collect(),
+            RenameRule::ScreamingSnake => field_name.to_ascii_uppercase(),
+        }
+    }
+}
+
+impl TryFrom<&LitStr> for RenameRule {
+    type Error = Error;
+
+    fn try_from(rule: &LitStr) -> Result<Self, Self::Error> {
+        match rule.value().as_str() {
+            "camelCase" => Ok(RenameRule::Camel),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            _ => Err(Error::new(
+                rule.span(),
+                format!("Unknown rename rule, expected one of: {}", ALL_RENAME_RULES.join(", ")),
+            )),
+        }
+    }
+}
+
+impl TryFrom<&Vec<Attribute>> for StructAttributes {
+    type Error = Error;
+
+    fn try_from(attrs: &Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut result = StructAttributes::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(DYNAMO_ATTRIBUTE_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(NESTED_ATTRIBUTE_NAME) {
+                    result.nested = true;
+                    Ok(())
+                } else if meta.path.is_ident(TRANSPARENT_ATTRIBUTE_NAME) {
+                    result.transparent = true;
+                    Ok(())
+                } else if meta.path.is_ident(STORE_NULL_ATTRIBUTE_NAME) {
+                    result.store_null = true;
+                    Ok(())
+                } else if meta.path.is_ident(DEFAULT_ATTRIBUTE_NAME) {
+                    result.default = true;
+                    Ok(())
+                } else if meta.path.is_ident(DENY_UNKNOWN_ATTRIBUTES_ATTRIBUTE_NAME) {
+                    result.deny_unknown_attributes = true;
+                    Ok(())
+                } else if meta.path.is_ident(RENAME_ALL_ATTRIBUTE_NAME) {
+                    result.rename_all = Some(RenameRule::try_from(&meta.value()?.parse::<LitStr>()?)?);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported dynamo attribute for a struct"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+// checks the attributes of the fields that only make sense once per struct, or in combination with those of the struct
+// two fields saved under the same name would overwrite each other, collisions with flattened fields are checked at compile time
+// the constant with the attribute name of a field called `schema` would collide with the schema of a struct with keys
+pub fn validate_field_attributes(fields: &Punctuated<Field, Comma>, struct_attributes: &StructAttributes) -> Result<(), Error> {
+    let extra_fields: Vec<&Field> = fields
+        .iter()
+        .filter(|f| FieldAttributes::try_from(&f.attrs).is_ok_and(|attributes| attributes.extra))
+        .collect();
+    let saved_fields: Vec<(&Field, String)> = fields
+        .iter()
+        .filter(|f| !FieldAttributes::try_from(&f.attrs).is_ok_and(|attributes| attributes.skip || attributes.extra || attributes.flatten))
+        .map(|f| (f, get_attribute_name(f, struct_attributes.rename_all)))
+        .collect();
+    let duplicate_names = saved_fields.iter().enumerate().filter_map(|(index, (f, name))| {
+        saved_fields[..index].iter().find(|(_, other)| other == name).map(|(other, _)| {
+            let other = other.ident.as_ref().unwrap();
+            Error::new_spanned(
+                f,
+                format!("Attribute name `{name}` is already used by field `{other}`, pick another one with `#[dynamo(rename = \"...\")]`"),
+            )
+        })
+    });
+
+    extra_fields
+        .iter()
+        .skip(1)
+        .map(|f| Error::new(f.span(), "Only one field can collect the extra attributes"))
+        .chain(extra_fields.iter().filter(|_| struct_attributes.deny_unknown_attributes).map(|f| {
+            Error::new(
+                f.span(),
+                "A field with `#[dynamo(extra)]` cannot be combined with `#[dynamo(deny_unknown_attributes)]`",
+            )
+        }))
+        .chain(duplicate_names)
+        .chain(
+            saved_fields
+                .iter()
+                .filter(|(f, _)| !struct_attributes.nested && attribute_name_constant(f) == SCHEMA_CONSTANT_NAME)
+                .map(|(f, name)| {
+                    let field = f.ident.as_ref().unwrap();
+                    Error::new_spanned(
+                        f,
+                        format!("The attribute name constant of field `{field}` would collide with `{SCHEMA_CONSTANT_NAME}`, the table schema of the struct. Rename the field and add `#[dynamo(rename = \"{name}\")]` to keep the attribute name"),
+                    )
+                }),
+        )
+        .reduce(|mut acc, e| {
+            acc.combine(e);
+            acc
+        })
+        .map_or(Ok(()), Err)
+}
+
+// options passed in with `#[dynamo(...)]` on a field
+#[derive(Debug, Default)]
+pub struct FieldAttributes {
+    pub list: bool,
+    // save a None as a DynamoDB null instead of leaving out the attribute
+    pub store_null: bool,
+    // module with the functions to convert the (innermost) type of the field, also set by `timestamp`, `as_string`, `serde` and `json`
+    pub with: Option<Path>,
+    // `serde` and `json` convert the whole field (apart from an Option) with `with`, instead of its innermost type
+    pub with_whole_field: bool,
+    // value to use when the attribute is missing
+    pub default: Option<FieldDefault>,
+    // name of the attribute, takes precedence over `rename_all` of the struct
+    pub rename: Option<String>,
+    // not saved, filled in with the default when reading
+    pub skip: bool,
+    // function that gets a reference to the value and returns true when it should not be saved
+    pub skip_serializing_if: Option<Path>,
+    // the attributes of the field (a struct) are saved next to those of the struct that contains it
+    pub flatten: bool,
+    // collects the attributes that do not belong to any other field
+    pub extra: bool,
+    // how to save the (innermost) type of the field, for type aliases we cannot see through
+    pub type_override: Option<TypeOverride>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TypeOverride {
+    String,
+    Number,
+    Boolean,
+    Binary,
+}
+
+impl TryFrom<&LitStr> for TypeOverride {
+    type Error = Error;
+
+    fn try_from(type_override: &LitStr) -> Result<Self, Self::Error> {
+        match type_override.value().as_str() {
+            "string" => Ok(TypeOverride::String),
+            "number" => Ok(TypeOverride::Number),
+            "boolean" => Ok(TypeOverride::Boolean),
+            "binary" => Ok(TypeOverride::Binary),
+            _ => Err(Error::new(
+                type_override.span(),
+                format!("Unknown type, expected one of: {}", ALL_TYPE_OVERRIDES.join(", ")),
+            )),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FieldDefault {
+    Trait,
+    Function(Path),
+}
+
+impl TryFrom<&Vec<Attribute>> for FieldAttributes {
+    type Error = Error;
+
+    fn try_from(attrs: &Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut result = FieldAttributes::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(DYNAMO_ATTRIBUTE_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(LIST_ATTRIBUTE_NAME) {
+                    result.list = true;
+                    Ok(())
+                } else if meta.path.is_ident(STORE_NULL_ATTRIBUTE_NAME) {
+                    result.store_null = true;
+                    Ok(())
+                } else if meta.path.is_ident(RENAME_ATTRIBUTE_NAME) {
+                    result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else if meta.path.is_ident(FLATTEN_ATTRIBUTE_NAME) {
+                    result.flatten = true;
+                    Ok(())
+                } else if meta.path.is_ident(TYPE_ATTRIBUTE_NAME) {
+                    result.type_override = Some(TypeOverride::try_from(&meta.value()?.parse::<LitStr>()?)?);
+                    Ok(())
+                } else if meta.path.is_ident(EXTRA_ATTRIBUTE_NAME) {
+                    result.extra = true;
+                    Ok(())
+                } else if meta.path.is_ident(SKIP_ATTRIBUTE_NAME) {
+                    result.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident(SKIP_SERIALIZING_IF_ATTRIBUTE_NAME) {
+                    result.skip_serializing_if = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                } else if meta.path.is_ident(DEFAULT_ATTRIBUTE_NAME) {
+                    if meta.input.peek(Token![=]) {
+                        result.default = Some(FieldDefault::Function(meta.value()?.parse::<LitStr>()?.parse::<Path>()?));
+                    } else {
+                        result.default = Some(FieldDefault::Trait);
+                    }
+                    Ok(())
+                } else if meta.path.is_ident(WITH_ATTRIBUTE_NAME) {
+                    result.with = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+                    Ok(())
+                } else if meta.path.is_ident(TIMESTAMP_ATTRIBUTE_NAME) {
+                    let encoding = meta.value()?.parse::<LitStr>()?;
+                    if !ALL_TIMESTAMP_ENCODINGS.contains(&encoding.value().as_str()) {
+                        return Err(Error::new(
+                            encoding.span(),
+                            format!(
+                                "Unknown timestamp encoding, expected one of: {}",
+                                ALL_TIMESTAMP_ENCODINGS.join(", ")
+                            ),
+                        ));
+                    }
+                    result.with = Some(syn::parse_str(&format!("dynamodb_helper::timestamp::{}", encoding.value()))?);
+                    Ok(())
+                } else if meta.path.is_ident(AS_STRING_ATTRIBUTE_NAME) {
+                    result.with = Some(syn::parse_str("dynamodb_helper::as_string")?);
+                    Ok(())
+                } else if meta.path.is_ident(SERDE_ATTRIBUTE_NAME) {
+                    result.with = Some(syn::parse_str("dynamodb_helper::document")?);
+                    result.with_whole_field = true;
+                    Ok(())
+                } else if meta.path.is_ident(JSON_ATTRIBUTE_NAME) {
+                    result.with = Some(syn::parse_str("dynamodb_helper::json")?);
+                    result.with_whole_field = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported dynamo attribute for a field"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
+
+// options passed in with `#[dynamo(...)]` on an enum
+#[derive(Debug)]
+pub struct EnumAttributes {
+    // name of the attribute that contains the variant, for enums saved as a map
+    pub tag: String,
+    // name of the attribute that contains the value of a tuple variant
+    pub content: String,
+}
+
+impl TryFrom<&Vec<Attribute>> for EnumAttributes {
+    type Error = Error;
+
+    fn try_from(attrs: &Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut result = EnumAttributes {
+            tag: DEFAULT_TAG_ATTRIBUTE.to_string(),
+            content: DEFAULT_CONTENT_ATTRIBUTE.to_string(),
+        };
+        // the last name that was set, to point at when the tag and content collide
+        let mut last_name: Option<LitStr> = None;
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(DYNAMO_ATTRIBUTE_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(TAG_ATTRIBUTE_NAME) {
+                    let name = meta.value()?.parse::<LitStr>()?;
+                    result.tag = name.value();
+                    last_name = Some(name);
+                    Ok(())
+                } else if meta.path.is_ident(CONTENT_ATTRIBUTE_NAME) {
+                    let name = meta.value()?.parse::<LitStr>()?;
+                    result.content = name.value();
+                    last_name = Some(name);
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported dynamo attribute for an enum"))
+                }
+            })?;
+        }
+
+        // the value of a tuple variant would overwrite its tag
+        if let Some(name) = last_name.filter(|_| result.tag == result.content) {
+            return Err(Error::new_spanned(name, "The tag and content attributes need different names"));
+        }
+
+        Ok(result)
+    }
+}
+
+// options passed in with `#[dynamo(...)]` on an enum variant
+#[derive(Debug, Default)]
+pub struct VariantAttributes {
+    pub rename: Option<String>,
+}
+
+impl TryFrom<&Vec<Attribute>> for VariantAttributes {
+    type Error = Error;
+
+    fn try_from(attrs: &Vec<Attribute>) -> Result<Self, Self::Error> {
+        let mut result = VariantAttributes::default();
+
+        for attr in attrs.iter().filter(|a| a.path().is_ident(DYNAMO_ATTRIBUTE_NAME)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident(RENAME_ATTRIBUTE_NAME) {
+                    result.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                    Ok(())
+                } else {
+                    Err(meta.error("Unsupported dynamo attribute for an enum variant"))
+                }
+            })?;
+        }
+
+        Ok(result)
+    }
+}
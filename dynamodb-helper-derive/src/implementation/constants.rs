@@ -0,0 +1,53 @@
+pub const NEW_METHOD_NAME: &str = "new";
+pub const BUILD_METHOD_NAME: &str = "build";
+
+pub const GET_METHOD_NAME: &str = "get"; // cover get by partition as well
+pub const BATCH_GET_METHOD_NAME: &str = "batch_get";
+pub const CREATE_TABLE_METHOD_NAME: &str = "create_table";
+pub const DELETE_TABLE_METHOD_NAME: &str = "delete_table";
+pub const PUT_METHOD_NAME: &str = "put";
+pub const BATCH_PUT_METHOD_NAME: &str = "batch_put";
+pub const DELETE_METHOD_NAME: &str = "delete";
+pub const SCAN_METHOD_NAME: &str = "scan";
+pub const TO_TABLE_METHOD_NAME: &str = "to_table";
+
+pub const EXCLUSION_ATTRIBUTE_NAME: &str = "exclusion";
+pub const PARTITION_KEY_ATTRIBUTE_NAME: &str = "partition";
+pub const RANGE_KEY_ATTRIBUTE_NAME: &str = "range";
+pub const DYNAMO_ATTRIBUTE_NAME: &str = "dynamo";
+
+pub const NESTED_ATTRIBUTE_NAME: &str = "nested";
+pub const LIST_ATTRIBUTE_NAME: &str = "list";
+pub const TRANSPARENT_ATTRIBUTE_NAME: &str = "transparent";
+pub const STORE_NULL_ATTRIBUTE_NAME: &str = "store_null";
+pub const DEFAULT_ATTRIBUTE_NAME: &str = "default";
+pub const SKIP_ATTRIBUTE_NAME: &str = "skip";
+pub const SKIP_SERIALIZING_IF_ATTRIBUTE_NAME: &str = "skip_serializing_if";
+pub const FLATTEN_ATTRIBUTE_NAME: &str = "flatten";
+pub const EXTRA_ATTRIBUTE_NAME: &str = "extra";
+pub const DENY_UNKNOWN_ATTRIBUTES_ATTRIBUTE_NAME: &str = "deny_unknown_attributes";
+pub const TYPE_ATTRIBUTE_NAME: &str = "type";
+pub const WITH_ATTRIBUTE_NAME: &str = "with";
+pub const TIMESTAMP_ATTRIBUTE_NAME: &str = "timestamp";
+pub const AS_STRING_ATTRIBUTE_NAME: &str = "as_string";
+pub const SERDE_ATTRIBUTE_NAME: &str = "serde";
+pub const JSON_ATTRIBUTE_NAME: &str = "json";
+pub const RENAME_ATTRIBUTE_NAME: &str = "rename";
+pub const RENAME_ALL_ATTRIBUTE_NAME: &str = "rename_all";
+pub const TAG_ATTRIBUTE_NAME: &str = "tag";
+pub const CONTENT_ATTRIBUTE_NAME: &str = "content";
+
+// the constant with the table schema, next to those with the attribute names
+pub const SCHEMA_CONSTANT_NAME: &str = "SCHEMA";
+
+pub const DEFAULT_TAG_ATTRIBUTE: &str = "type";
+pub const DEFAULT_CONTENT_ATTRIBUTE: &str = "content";
+
+pub const ALL_NUMERIC_TYPES_AS_STRINGS: &[&str] = &["u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64"];
+pub const ALL_LIST_TYPES_AS_STRINGS: &[&str] = &["Vec", "VecDeque"];
+pub const ALL_MAP_TYPES_AS_STRINGS: &[&str] = &["HashMap", "BTreeMap"];
+pub const ALL_SET_TYPES_AS_STRINGS: &[&str] = &["HashSet", "BTreeSet"];
+pub const ALL_TIMESTAMP_ENCODINGS: &[&str] = &["iso8601", "epoch_seconds", "epoch_millis"];
+pub const ALL_RENAME_RULES: &[&str] = &["camelCase", "PascalCase", "SCREAMING_SNAKE_CASE"];
+pub const ALL_TYPE_OVERRIDES: &[&str] = &["string", "number", "boolean", "binary"];
+pub const ALL_WRAPPER_TYPES_AS_STRINGS: &[&str] = &["Box", "Arc", "Rc", "Cow"];
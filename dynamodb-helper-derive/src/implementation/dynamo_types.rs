@@ -0,0 +1,440 @@
+use crate::implementation::{
+    get_inner_types, matches_any_type, matches_type, FieldAttributes, TypeOverride, ALL_LIST_TYPES_AS_STRINGS, ALL_MAP_TYPES_AS_STRINGS,
+    ALL_NUMERIC_TYPES_AS_STRINGS, ALL_SET_TYPES_AS_STRINGS, ALL_WRAPPER_TYPES_AS_STRINGS,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Error, Expr, Field, Path, Type};
+
+// in DynamoDB you wrap your values in the right 'attribute values', like N for numbers
+// see for example https://docs.aws.amazon.com/amazondynamodb/latest/APIReference/API_AttributeValue.html
+// so we need to find the right attribute value for the given input
+
+#[derive(Debug)]
+pub enum PossiblyOptionalDynamoType {
+    Normal(IterableDynamoType),
+    Optional(IterableDynamoType),
+}
+
+impl TryFrom<&Field> for PossiblyOptionalDynamoType {
+    type Error = Error;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        let attributes = FieldAttributes::try_from(&field.attrs)?;
+        let value = &field.ty;
+
+        if attributes.flatten && matches_type(value, "Option") {
+            Err(Error::new(value.span(), "Flattened fields cannot be optional"))
+        } else if matches_type(value, "Option") {
+            let inner = single_inner_type(value, "Expected this option type to have an inner type")?;
+            Ok(PossiblyOptionalDynamoType::Optional(IterableDynamoType::parse_field_type(inner, &attributes)?))
+        } else {
+            Ok(PossiblyOptionalDynamoType::Normal(IterableDynamoType::parse_field_type(value, &attributes)?))
+        }
+    }
+}
+
+impl PossiblyOptionalDynamoType {
+    // for values that are not saved as an attribute of a map, where a None has to become a DynamoDB null
+    pub fn into_iterable(self) -> IterableDynamoType {
+        match self {
+            PossiblyOptionalDynamoType::Normal(v) => v,
+            PossiblyOptionalDynamoType::Optional(v) => IterableDynamoType::Optional(Box::new(v)),
+        }
+    }
+}
+
+// lists, maps and options can be nested as deep as you like, so this is a recursive structure
+#[derive(Debug)]
+pub enum IterableDynamoType {
+    Simple(DynamoType),
+    // an option inside a list or map, None is saved as a DynamoDB null
+    Optional(Box<IterableDynamoType>),
+    List(Box<IterableDynamoType>),
+    // a fixed-size array, saved as a list, with the length checked when reading
+    Array(Box<IterableDynamoType>, Box<Expr>),
+    // DynamoDB map keys are always strings, so we only keep track of the value type
+    Map(Box<IterableDynamoType>),
+    // only allowed as the type of a field, because DynamoDB does not accept empty sets
+    Set(DynamoType),
+    // saved as the value inside the wrapper
+    Wrapped(Wrapper, Box<IterableDynamoType>),
+}
+
+impl IterableDynamoType {
+    fn parse_field_type(value: &Type, attributes: &FieldAttributes) -> Result<Self, Error> {
+        if matches_any_type(value, ALL_SET_TYPES_AS_STRINGS.to_vec()) && !attributes.list && !attributes.with_whole_field {
+            let inner = single_inner_type(value, "Expected this set type to have an inner type")?;
+
+            return match DynamoType::from(inner) {
+                Some(simple @ (DynamoType::String | DynamoType::Number | DynamoType::Binary(_))) => Ok(IterableDynamoType::Set(simple)),
+                _ => Err(Error::new(inner.span(), "DynamoDB only supports sets of strings, numbers and binaries")),
+            };
+        }
+        IterableDynamoType::parse(value, attributes)
+    }
+
+    fn parse(value: &Type, attributes: &FieldAttributes) -> Result<Self, Error> {
+        if matches_type(value, "Option") {
+            let inner = single_inner_type(value, "Expected this option type to have an inner type")?;
+            Ok(IterableDynamoType::Optional(Box::new(IterableDynamoType::parse(inner, attributes)?)))
+        } else if let Some(path) = attributes.with.as_ref().filter(|_| attributes.with_whole_field) {
+            Ok(IterableDynamoType::Simple(DynamoType::With(Box::new(path.clone()))))
+        } else if let Some(wrapper) = Wrapper::from(value) {
+            let inner = single_inner_type(value, "Expected this type to have an inner type")?;
+            match wrapper {
+                Wrapper::Cow if matches!(inner, Type::Path(p) if p.path.is_ident("str")) => {
+                    Ok(IterableDynamoType::Wrapped(wrapper, Box::new(IterableDynamoType::Simple(DynamoType::String))))
+                }
+                Wrapper::Cow if matches!(inner, Type::Slice(slice) if matches_type(&slice.elem, "u8")) => {
+                    Ok(IterableDynamoType::Wrapped(wrapper, Box::new(IterableDynamoType::Simple(DynamoType::Binary(BinaryType::Vec)))))
+                }
+                Wrapper::Cow => Err(Error::new(inner.span(), "Only `Cow<str>` and `Cow<[u8]>` are supported")),
+                _ => Ok(IterableDynamoType::Wrapped(wrapper, Box::new(IterableDynamoType::parse(inner, attributes)?))),
+            }
+        } else if let Some(binary) = BinaryType::from(value).filter(|_| !(attributes.list && is_list_of_bytes(value)) && attributes.with.is_none()) {
+            // a Vec<u8> or [u8; N] is binary, unless you explicitly ask for a list
+            Ok(IterableDynamoType::Simple(DynamoType::Binary(binary)))
+        } else if matches_any_type(value, ALL_LIST_TYPES_AS_STRINGS.to_vec()) {
+            let inner = single_inner_type(value, "Expected this list type to have an inner type")?;
+            Ok(IterableDynamoType::List(Box::new(IterableDynamoType::parse(inner, attributes)?)))
+        } else if let Type::Array(array) = value {
+            Ok(IterableDynamoType::Array(
+                Box::new(IterableDynamoType::parse(&array.elem, attributes)?),
+                Box::new(array.len.clone()),
+            ))
+        } else if matches_any_type(value, ALL_SET_TYPES_AS_STRINGS.to_vec()) {
+            if !attributes.list {
+                return Err(Error::new(
+                    value.span(),
+                    "Sets inside lists or maps are not supported, add `#[dynamo(list)]` to the field to save them as lists",
+                ));
+            }
+            let inner = single_inner_type(value, "Expected this set type to have an inner type")?;
+            Ok(IterableDynamoType::List(Box::new(IterableDynamoType::parse(inner, attributes)?)))
+        } else if is_map_type(value) {
+            let map_args = get_inner_types(value);
+            let map_key = map_args.first().ok_or(Error::new(value.span(), "Expected this map type to have a key argument"))?;
+            let map_value = map_args.get(1).ok_or(Error::new(value.span(), "Expected this map type to have a value argument"))?;
+
+            if !matches!(DynamoType::from(map_key), Some(DynamoType::String)) {
+                return Err(Error::new(map_key.span(), "DynamoDB only supports strings as map keys"));
+            }
+
+            Ok(IterableDynamoType::Map(Box::new(IterableDynamoType::parse(map_value, attributes)?)))
+        } else if let Some(path) = &attributes.with {
+            Ok(IterableDynamoType::Simple(DynamoType::With(Box::new(path.clone()))))
+        } else if let Some(type_override) = attributes.type_override {
+            Ok(IterableDynamoType::Simple(DynamoType::from_type_override(type_override)))
+        } else {
+            Ok(IterableDynamoType::Simple(
+                DynamoType::from_field_type(value).ok_or(Error::new(value.span(), "Did not find a valid DynamoDB type".to_string()))?,
+            ))
+        }
+    }
+}
+
+impl IterableDynamoType {
+    // an expression for the `Option<dynamodb_helper::AttributeType>` of the schema, usable in a const
+    pub fn attribute_type(&self) -> TokenStream {
+        let attribute_type = |variant: &str| {
+            let variant = Ident::new(variant, proc_macro2::Span::call_site());
+            quote!(Some(dynamodb_helper::AttributeType::#variant))
+        };
+
+        match self {
+            IterableDynamoType::Simple(DynamoType::String) => attribute_type("String"),
+            IterableDynamoType::Simple(DynamoType::Number) => attribute_type("Number"),
+            IterableDynamoType::Simple(DynamoType::Boolean) => attribute_type("Boolean"),
+            IterableDynamoType::Simple(DynamoType::Binary(_)) => attribute_type("Binary"),
+            IterableDynamoType::Simple(DynamoType::Nested(ty)) => quote!(<#ty as dynamodb_helper::TryIntoAttributeValue>::ATTRIBUTE_TYPE),
+            // only the modules of this crate tell us the type they save
+            IterableDynamoType::Simple(DynamoType::With(path)) if path.segments.first().is_some_and(|first| first.ident == "dynamodb_helper") => {
+                quote!(#path::ATTRIBUTE_TYPE)
+            }
+            IterableDynamoType::Simple(DynamoType::With(_)) => quote!(None),
+            IterableDynamoType::Optional(inner) | IterableDynamoType::Wrapped(_, inner) => inner.attribute_type(),
+            IterableDynamoType::List(_) | IterableDynamoType::Array(_, _) => attribute_type("List"),
+            IterableDynamoType::Map(_) => attribute_type("Map"),
+            IterableDynamoType::Set(DynamoType::Number) => attribute_type("NumberSet"),
+            IterableDynamoType::Set(DynamoType::Binary(_)) => attribute_type("BinarySet"),
+            IterableDynamoType::Set(_) => attribute_type("StringSet"),
+        }
+    }
+}
+
+// checks all field types up front, so we can report every invalid type with a clear error
+// skipped fields are not saved, so they can have any type, the extra attributes are saved as they are
+pub fn validate_field_types(fields: &Punctuated<Field, Comma>) -> Result<(), Error> {
+    fields
+        .iter()
+        .filter(|f| !FieldAttributes::try_from(&f.attrs).is_ok_and(|attributes| attributes.skip || attributes.extra))
+        .filter_map(|f| PossiblyOptionalDynamoType::try_from(f).err())
+        .reduce(|mut acc, e| {
+            acc.combine(e);
+            acc
+        })
+        .map_or(Ok(()), Err)
+}
+
+fn is_list_of_bytes(ty: &Type) -> bool {
+    matches_type(ty, "Vec") || matches!(ty, Type::Array(_))
+}
+
+// an IndexMap is only recognized with the indexmap feature, your own crate should depend on indexmap
+fn is_map_type(ty: &Type) -> bool {
+    matches_any_type(ty, ALL_MAP_TYPES_AS_STRINGS.to_vec()) || (cfg!(feature = "indexmap") && matches_type(ty, "IndexMap"))
+}
+
+fn single_inner_type<'a>(value: &'a Type, message: &str) -> Result<&'a Type, Error> {
+    get_inner_types(value)
+        .first()
+        .copied()
+        .ok_or(Error::new(value.span(), message.to_string()))
+}
+
+#[derive(Debug)]
+pub enum DynamoType {
+    Number,
+    String,
+    Boolean,
+    Binary(BinaryType),
+    // a type implementing the conversion traits of dynamodb_helper, like another struct deriving DynamoDb
+    Nested(Box<Type>),
+    // a type converted by the functions of the module passed in with `#[dynamo(with = "...")]`
+    With(Box<Path>),
+}
+
+impl DynamoType {
+    pub fn from(ty: &Type) -> Option<Self> {
+        if matches_any_type(ty, ALL_NUMERIC_TYPES_AS_STRINGS.to_vec()) {
+            Some(DynamoType::Number)
+        } else if matches_type(ty, "bool") {
+            Some(DynamoType::Boolean)
+        } else if matches_type(ty, "String") {
+            Some(DynamoType::String)
+        } else {
+            BinaryType::from(ty).map(DynamoType::Binary)
+        }
+    }
+
+    fn from_type_override(type_override: TypeOverride) -> Self {
+        match type_override {
+            TypeOverride::String => DynamoType::String,
+            TypeOverride::Number => DynamoType::Number,
+            TypeOverride::Boolean => DynamoType::Boolean,
+            TypeOverride::Binary => DynamoType::Binary(BinaryType::Vec),
+        }
+    }
+
+    // for fields we assume that anything we do not recognize implements the conversion traits, e.g. because it derives DynamoDb
+    pub fn from_field_type(ty: &Type) -> Option<Self> {
+        DynamoType::from(ty).or_else(|| match ty {
+            Type::Path(_) => Some(DynamoType::Nested(Box::new(ty.clone()))),
+            _ => None,
+        })
+    }
+}
+
+// DynamoDB keys can only be strings, numbers or binaries
+#[derive(Debug)]
+pub enum KeyType {
+    String,
+    Number,
+    Binary(BinaryType),
+    // a type implementing `dynamodb_helper::KeyAttribute` and the conversion traits, like an enum deriving DynamoDb
+    Custom(Box<Type>),
+    // a `#[dynamo(with = "...")]` module that also has a `scalar_attribute_type` function
+    With(Box<Path>),
+}
+
+impl TryFrom<&Field> for KeyType {
+    type Error = Error;
+
+    fn try_from(field: &Field) -> Result<Self, Self::Error> {
+        let attributes = FieldAttributes::try_from(&field.attrs)?;
+        if attributes.skip {
+            return Err(Error::new(field.span(), "Keys are always saved, so they cannot be skipped"));
+        }
+        match (attributes.with, attributes.type_override) {
+            (Some(path), _) => Ok(KeyType::With(Box::new(path))),
+            (None, Some(TypeOverride::String)) => Ok(KeyType::String),
+            (None, Some(TypeOverride::Number)) => Ok(KeyType::Number),
+            (None, Some(TypeOverride::Binary)) => Ok(KeyType::Binary(BinaryType::Vec)),
+            (None, Some(TypeOverride::Boolean)) => {
+                Err(Error::new(field.ty.span(), "DynamoDB does not support booleans as keys, only strings, numbers and binaries"))
+            }
+            (None, None) => KeyType::try_from(&field.ty),
+        }
+    }
+}
+
+impl TryFrom<&Type> for KeyType {
+    type Error = Error;
+
+    fn try_from(value: &Type) -> Result<Self, Self::Error> {
+        match DynamoType::from(value) {
+            Some(DynamoType::String) => Ok(KeyType::String),
+            Some(DynamoType::Number) => Ok(KeyType::Number),
+            Some(DynamoType::Binary(binary)) => Ok(KeyType::Binary(binary)),
+            Some(DynamoType::Boolean) => Err(Error::new(
+                value.span(),
+                "DynamoDB does not support booleans as keys, only strings, numbers and binaries",
+            )),
+            _ if matches_type(value, "Option") => Err(Error::new(value.span(), "Keys are required, so they cannot be optional")),
+            _ if is_map_type(value) || matches_any_type(value, [ALL_LIST_TYPES_AS_STRINGS, ALL_SET_TYPES_AS_STRINGS].concat()) => Err(Error::new(
+                value.span(),
+                "DynamoDB does not support lists, maps or sets as keys, only strings, numbers and binaries",
+            )),
+            _ if matches!(value, Type::Path(_)) => Ok(KeyType::Custom(Box::new(value.clone()))),
+            _ => Err(Error::new(value.span(), "DynamoDB only supports strings, numbers and binaries as keys")),
+        }
+    }
+}
+
+impl KeyType {
+    pub fn attribute_type_value(&self, name_of_attribute: Ident) -> TokenStream {
+        match self {
+            KeyType::String => {
+                quote! {
+                    aws_sdk_dynamodb::types::AttributeValue::S(#name_of_attribute)
+                }
+            }
+            KeyType::Number => {
+                quote! {
+                    aws_sdk_dynamodb::types::AttributeValue::N(#name_of_attribute.to_string())
+                }
+            }
+            KeyType::Binary(binary) => {
+                let blob = binary.value_to_blob(quote!(#name_of_attribute));
+                quote! {
+                    aws_sdk_dynamodb::types::AttributeValue::B(#blob)
+                }
+            }
+            KeyType::Custom(_) => {
+                quote! {
+                    dynamodb_helper::IntoAttributeValue::into_attribute_value(#name_of_attribute)
+                }
+            }
+            KeyType::With(path) => {
+                quote! {
+                    #path::into_attribute_value(#name_of_attribute)
+                }
+            }
+        }
+    }
+
+    pub fn scalar_attribute_type(&self) -> TokenStream {
+        match self {
+            KeyType::String => {
+                quote! {
+                    aws_sdk_dynamodb::types::ScalarAttributeType::S
+                }
+            }
+            KeyType::Number => {
+                quote! {
+                    aws_sdk_dynamodb::types::ScalarAttributeType::N
+                }
+            }
+            KeyType::Binary(_) => {
+                quote! {
+                    aws_sdk_dynamodb::types::ScalarAttributeType::B
+                }
+            }
+            KeyType::Custom(ty) => {
+                quote! {
+                    <#ty as dynamodb_helper::KeyAttribute>::scalar_attribute_type()
+                }
+            }
+            KeyType::With(path) => {
+                quote! {
+                    #path::scalar_attribute_type()
+                }
+            }
+        }
+    }
+}
+
+// smart pointers and the like, saved as the value they wrap
+#[derive(Debug)]
+pub enum Wrapper {
+    Box,
+    Arc,
+    Rc,
+    Cow,
+}
+
+impl Wrapper {
+    pub fn from(ty: &Type) -> Option<Self> {
+        match ty {
+            _ if !matches_any_type(ty, ALL_WRAPPER_TYPES_AS_STRINGS.to_vec()) => None,
+            _ if matches_type(ty, "Box") => Some(Wrapper::Box),
+            _ if matches_type(ty, "Arc") => Some(Wrapper::Arc),
+            _ if matches_type(ty, "Rc") => Some(Wrapper::Rc),
+            _ => Some(Wrapper::Cow),
+        }
+    }
+
+    // takes the (owned) value out of the wrapper, cloning it when it is shared
+    pub fn unwrap(&self, value: TokenStream) -> TokenStream {
+        match self {
+            Wrapper::Box => quote!((*#value)),
+            Wrapper::Arc => quote!(std::sync::Arc::unwrap_or_clone(#value)),
+            Wrapper::Rc => quote!(std::rc::Rc::unwrap_or_clone(#value)),
+            Wrapper::Cow => quote!(#value.into_owned()),
+        }
+    }
+
+    // the function that puts a value in the wrapper
+    pub fn wrap(&self) -> TokenStream {
+        match self {
+            Wrapper::Box => quote!(Box::new),
+            Wrapper::Arc => quote!(std::sync::Arc::new),
+            Wrapper::Rc => quote!(std::rc::Rc::new),
+            Wrapper::Cow => quote!(std::borrow::Cow::Owned),
+        }
+    }
+}
+
+// the Rust types we save as DynamoDB binary, each needs a slightly different conversion from and to a blob
+#[derive(Debug)]
+pub enum BinaryType {
+    Vec,
+    Array,
+    Blob,
+    Bytes,
+}
+
+impl BinaryType {
+    pub fn from(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::Array(array) if matches_type(&array.elem, "u8") => Some(BinaryType::Array),
+            _ if matches_type(ty, "Vec") && get_inner_types(ty).first().is_some_and(|inner| matches_type(inner, "u8")) => Some(BinaryType::Vec),
+            _ if matches_type(ty, "Blob") => Some(BinaryType::Blob),
+            _ if cfg!(feature = "bytes") && matches_type(ty, "Bytes") => Some(BinaryType::Bytes),
+            _ => None,
+        }
+    }
+
+    pub fn value_to_blob(&self, value: TokenStream) -> TokenStream {
+        match self {
+            BinaryType::Blob => value,
+            _ => quote!(aws_sdk_dynamodb::primitives::Blob::new(#value)),
+        }
+    }
+
+    // expects a reference to a blob named `v`, results in a Result of the Rust value
+    pub fn blob_to_value(&self, name_as_string: &str, err: &Ident) -> TokenStream {
+        match self {
+            BinaryType::Vec => quote!(Ok(v.as_ref().to_vec())),
+            BinaryType::Array => {
+                quote!(v.as_ref().try_into().map_err(|_| #err::new(format!("Could not convert {} to a byte array of the expected length", #name_as_string))))
+            }
+            BinaryType::Blob => quote!(Ok(v.clone())),
+            BinaryType::Bytes => quote!(Ok(bytes::Bytes::copy_from_slice(v.as_ref()))),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::implementation::{get_attribute_name, insert_into_hashmap_for_field, value_from_hashmap_for_field};
+use crate::{
+    from_struct_for_attribute_value, into_attribute_value_for, try_from_attribute_value_for, try_from_attribute_value_to_struct, EnumAttributes,
+    VariantAttributes,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{Attribute, DataEnum, Error, Field, Fields, Variant};
+
+// the fields of every variant, so their types can be validated like those of a struct
+pub fn fields_of_variants(data: &DataEnum) -> Vec<&Punctuated<Field, Comma>> {
+    data.variants
+        .iter()
+        .filter_map(|v| match &v.fields {
+            Fields::Named(named) => Some(&named.named),
+            Fields::Unnamed(unnamed) => Some(&unnamed.unnamed),
+            Fields::Unit => None,
+        })
+        .collect()
+}
+
+pub fn enum_conversions(enum_name: &Ident, error: &Ident, data: &DataEnum, attrs: &Vec<Attribute>) -> Result<TokenStream, Error> {
+    let enum_attributes = EnumAttributes::try_from(attrs)?;
+    let variants = data
+        .variants
+        .iter()
+        .map(|v| VariantAttributes::try_from(&v.attrs).map(|attributes| (v, attributes.rename.unwrap_or_else(|| v.ident.to_string()))))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if variants.iter().all(|(v, _)| matches!(v.fields, Fields::Unit)) {
+        Ok(unit_enum_conversions(enum_name, error, &variants))
+    } else {
+        tagged_enum_conversions(enum_name, error, &variants, &enum_attributes)
+    }
+}
+
+fn expected_variants(variants: &[(&Variant, String)]) -> String {
+    variants.iter().map(|(_, name)| name.as_str()).collect::<Vec<_>>().join(", ")
+}
+
+// enums without any data are saved as a DynamoDB string, which means they can also serve as keys
+fn unit_enum_conversions(enum_name: &Ident, error: &Ident, variants: &[(&Variant, String)]) -> TokenStream {
+    let expected = expected_variants(variants);
+    let into_attribute_value = into_attribute_value_for(enum_name, quote!(Some(dynamodb_helper::AttributeType::String)));
+    let try_from_attribute_value = try_from_attribute_value_for(enum_name, error);
+    let to_string_arms = variants.iter().map(|(v, name)| {
+        let ident = &v.ident;
+        quote!(#enum_name::#ident => #name,)
+    });
+    let from_string_arms = variants.iter().map(|(v, name)| {
+        let ident = &v.ident;
+        quote!(#name => Ok(#enum_name::#ident),)
+    });
+
+    quote! {
+        impl From<#enum_name> for aws_sdk_dynamodb::types::AttributeValue {
+            fn from(input: #enum_name) -> Self {
+                let variant = match input {
+                    #(#to_string_arms)*
+                };
+                aws_sdk_dynamodb::types::AttributeValue::S(variant.to_string())
+            }
+        }
+
+        impl TryFrom<&aws_sdk_dynamodb::types::AttributeValue> for #enum_name {
+            type Error = #error;
+
+            fn try_from(value: &aws_sdk_dynamodb::types::AttributeValue) -> Result<Self, Self::Error> {
+                let variant = value.as_s().map_err(|_| #error::new("Could not convert from Dynamo String".to_string()))?;
+                match variant.as_str() {
+                    #(#from_string_arms)*
+                    other => Err(#error::new(format!("Unknown variant '{}', expected one of: {}", other, #expected))),
+                }
+            }
+        }
+
+        #into_attribute_value
+        #try_from_attribute_value
+
+        impl dynamodb_helper::KeyAttribute for #enum_name {
+            fn scalar_attribute_type() -> aws_sdk_dynamodb::types::ScalarAttributeType {
+                aws_sdk_dynamodb::types::ScalarAttributeType::S
+            }
+        }
+    }
+}
+
+// enums with data are saved as a DynamoDB map, with the variant in the tag attribute
+// named fields are saved next to the tag, the value of a tuple variant is saved in the content attribute
+fn tagged_enum_conversions(
+    enum_name: &Ident,
+    error: &Ident,
+    variants: &[(&Variant, String)],
+    enum_attributes: &EnumAttributes,
+) -> Result<TokenStream, Error> {
+    let tag = &enum_attributes.tag;
+    let content = &enum_attributes.content;
+    let expected = expected_variants(variants);
+
+    let mut inserts = vec![];
+    let mut retrievals = vec![];
+    let mut errors: Option<Error> = None;
+
+    for (v, name) in variants {
+        let ident = &v.ident;
+
+        match &v.fields {
+            Fields::Unit => {
+                inserts.push(quote! {
+                    #enum_name::#ident => {
+                        map.insert(#tag.to_string(), aws_sdk_dynamodb::types::AttributeValue::S(#name.to_string()));
+                    }
+                });
+                retrievals.push(quote!(#name => Ok(#enum_name::#ident),));
+            }
+            Fields::Named(named) => {
+                if let Some(f) = named.named.iter().find(|f| get_attribute_name(f, None) == *tag) {
+                    let e = Error::new_spanned(f, format!("Attribute name collides with the tag attribute `{tag}`, use `#[dynamo(tag = \"...\")]` to pick another tag"));
+                    errors = combine(errors, e);
+                    continue;
+                }
+
+                let field_names: Vec<&Ident> = named.named.iter().map(|f| f.ident.as_ref().unwrap()).collect();
+                let bindings: Vec<Ident> = field_names.iter().map(|n| format_ident!("field_{}", n)).collect();
+                let field_inserts = named
+                    .named
+                    .iter()
+                    .zip(bindings.iter())
+                    .map(|(f, binding)| insert_into_hashmap_for_field(f, &get_attribute_name(f, None), quote!(#binding), false));
+                let field_retrievals = named.named.iter().map(|f| {
+                    let field_name = f.ident.as_ref().unwrap();
+                    let value = value_from_hashmap_for_field(f, &get_attribute_name(f, None), error, false);
+                    quote!(#field_name: #value,)
+                });
+
+                inserts.push(quote! {
+                    #enum_name::#ident { #(#field_names: #bindings),* } => {
+                        map.insert(#tag.to_string(), aws_sdk_dynamodb::types::AttributeValue::S(#name.to_string()));
+                        #(#field_inserts)*
+                    }
+                });
+                retrievals.push(quote! {
+                    #name => Ok(#enum_name::#ident {
+                        #(#field_retrievals)*
+                    }),
+                });
+            }
+            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                let f = &unnamed.unnamed[0];
+                let field_insert = insert_into_hashmap_for_field(f, content, quote!(field_0), false);
+                let field_retrieval = value_from_hashmap_for_field(f, content, error, false);
+
+                inserts.push(quote! {
+                    #enum_name::#ident(field_0) => {
+                        map.insert(#tag.to_string(), aws_sdk_dynamodb::types::AttributeValue::S(#name.to_string()));
+                        #field_insert
+                    }
+                });
+                retrievals.push(quote!(#name => Ok(#enum_name::#ident(#field_retrieval)),));
+            }
+            Fields::Unnamed(unnamed) => {
+                let e = Error::new_spanned(
+                    unnamed,
+                    "Tuple variants with more than one field are not supported, use named fields instead",
+                );
+                errors = combine(errors, e);
+            }
+        }
+    }
+
+    if let Some(e) = errors {
+        return Err(e);
+    }
+
+    let from_enum_for_attribute_value = from_struct_for_attribute_value(enum_name);
+    let try_from_attribute_value_for_enum = try_from_attribute_value_to_struct(enum_name, error);
+
+    Ok(quote! {
+        impl TryFrom<#enum_name> for std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue> {
+            type Error = dynamodb_helper::ValidationError;
+
+            fn try_from(input: #enum_name) -> Result<Self, Self::Error> {
+                let mut map = std::collections::HashMap::new();
+                match input {
+                    #(#inserts)*
+                }
+                Ok(map)
+            }
+        }
+
+        impl TryFrom<&std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #enum_name {
+            type Error = #error;
+
+            fn try_from(map: &std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                let variant = map
+                    .get(#tag)
+                    .ok_or_else(|| #error::new(format!("Did not find required attribute {}", #tag)))?
+                    .as_s()
+                    .map_err(|_| #error::new(format!("Could not convert {} from Dynamo String", #tag)))?;
+                match variant.as_str() {
+                    #(#retrievals)*
+                    other => Err(#error::new(format!("Unknown variant '{}', expected one of: {}", other, #expected))),
+                }
+            }
+        }
+
+        impl TryFrom<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #enum_name {
+            type Error = #error;
+
+            fn try_from(map: std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #enum_name::try_from(&map)
+            }
+        }
+
+        #from_enum_for_attribute_value
+        #try_from_attribute_value_for_enum
+    })
+}
+
+fn combine(errors: Option<Error>, e: Error) -> Option<Error> {
+    match errors {
+        Some(mut acc) => {
+            acc.combine(e);
+            Some(acc)
+        }
+        None => Some(e),
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{tokenstream_or_empty_if_no_retrieval_methods, BATCH_GET_METHOD_NAME, GET_METHOD_NAME, SCAN_METHOD_NAME};
+use crate::{BATCH_GET_METHOD_NAME, BATCH_PUT_METHOD_NAME, GET_METHOD_NAME, PUT_METHOD_NAME, SCAN_METHOD_NAME};
 use proc_macro2::Ident;
 use quote::quote;
 
@@ -12,6 +12,13 @@ pub fn generate_error_names(helper_name: &Ident) -> (Ident, Ident, Ident, Ident,
     (get_error, get_by_partition_error, batch_get_error, scan_error, parse_error)
 }
 
+pub fn generate_put_error_names(helper_name: &Ident) -> (Ident, Ident) {
+    let put_error = Ident::new(&format!("{helper_name}PutError"), helper_name.span());
+    let batch_put_error = Ident::new(&format!("{helper_name}BatchPutError"), helper_name.span());
+
+    (put_error, batch_put_error)
+}
+
 pub fn generate_helper_error(struct_name: &Ident, exclusions: &[&str]) -> proc_macro2::TokenStream {
     let (get_error, get_by_partition_error, batch_get_error, scan_error, parse_error) = generate_error_names(struct_name);
 
@@ -47,15 +54,38 @@ pub fn generate_helper_error(struct_name: &Ident, exclusions: &[&str]) -> proc_m
         .filter(|error_name| !exclusions.contains(&error_name.3))
         .map(|error_name| generate_impl_error(error_name.0, &error_name.1, &error_name.2, &parse_error));
 
-    let parse_error_stream = tokenstream_or_empty_if_no_retrieval_methods(generate_parse_error(&parse_error), exclusions);
+    let (put_error, batch_put_error) = generate_put_error_names(struct_name);
+
+    let put_error_copies = [
+        (
+            &put_error,
+            Ident::new("put_item", struct_name.span()),
+            Ident::new("PutItemError", struct_name.span()),
+            PUT_METHOD_NAME,
+        ),
+        (
+            &batch_put_error,
+            Ident::new("batch_write_item", struct_name.span()),
+            Ident::new("BatchWriteItemError", struct_name.span()),
+            BATCH_PUT_METHOD_NAME,
+        ),
+    ];
+
+    let impl_put_errors = put_error_copies
+        .iter()
+        .filter(|error_name| !exclusions.contains(&error_name.3))
+        .map(|error_name| generate_impl_put_error(error_name.0, &error_name.1, &error_name.2));
+
+    let parse_error_stream = generate_parse_error(&parse_error);
 
     quote! {
         #parse_error_stream
         #(#impl_errors)*
+        #(#impl_put_errors)*
     }
 }
 
-fn generate_parse_error(parse_error: &Ident) -> proc_macro2::TokenStream {
+pub fn generate_parse_error(parse_error: &Ident) -> proc_macro2::TokenStream {
     quote! {
         #[derive(Debug)]
         pub struct #parse_error {
@@ -112,6 +142,15 @@ fn generate_impl_error(error: &Ident, error_package: &Ident, aws_error: &Ident,
             }
         }
 
+        impl From<dynamodb_helper::TableReadError<aws_sdk_dynamodb::operation::#error_package::#aws_error, #parse_error>> for #error {
+            fn from(err: dynamodb_helper::TableReadError<aws_sdk_dynamodb::operation::#error_package::#aws_error, #parse_error>) -> Self {
+                match err {
+                    dynamodb_helper::TableReadError::ParseError(err) => err.into(),
+                    dynamodb_helper::TableReadError::AwsError(err) => err.into(),
+                }
+            }
+        }
+
         impl std::fmt::Display for #error {
             fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
                 match self {
@@ -122,3 +161,48 @@ fn generate_impl_error(error: &Ident, error_package: &Ident, aws_error: &Ident,
         }
     }
 }
+
+// put methods check the item before sending it, so besides an aws error they can return a validation error
+fn generate_impl_put_error(error: &Ident, error_package: &Ident, aws_error: &Ident) -> proc_macro2::TokenStream {
+    let error_name = error.to_string();
+
+    quote! {
+        #[derive(Debug)]
+        pub enum #error {
+            ValidationError(dynamodb_helper::ValidationError),
+            AwsError(aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::#error_package::#aws_error>),
+        }
+
+        impl std::error::Error for #error {}
+
+        impl From<aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::#error_package::#aws_error>> for #error {
+            fn from(err: aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::#error_package::#aws_error>) -> Self {
+                #error::AwsError(err)
+            }
+        }
+
+        impl From<dynamodb_helper::ValidationError> for #error {
+            fn from(err: dynamodb_helper::ValidationError) -> Self {
+                #error::ValidationError(err)
+            }
+        }
+
+        impl From<dynamodb_helper::TableWriteError<aws_sdk_dynamodb::operation::#error_package::#aws_error>> for #error {
+            fn from(err: dynamodb_helper::TableWriteError<aws_sdk_dynamodb::operation::#error_package::#aws_error>) -> Self {
+                match err {
+                    dynamodb_helper::TableWriteError::ValidationError(err) => err.into(),
+                    dynamodb_helper::TableWriteError::AwsError(err) => err.into(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for #error {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #error::ValidationError(val) => write!(f, "{} validation error: {}", &#error_name, val),
+                    #error::AwsError(val) => write!(f, "{} aws error {}", &#error_name, val)
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::implementation::KeyType;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Field;
+
+// lets generic code, like `dynamodb_helper::Table`, work with the struct through the conversions and key struct the macro generates
+pub fn dynamo_item_for_struct(
+    struct_name: &Ident,
+    key_name: &Ident,
+    parse_error: &Ident,
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> TokenStream {
+    let partition_key_name = &partition_key_name_and_field.0;
+    let partition_key_type = &partition_key_name_and_field.1.ty;
+    let range_key_name = match range_key_name_and_field {
+        Some((name, _)) => quote!(Some(#name)),
+        None => quote!(None),
+    };
+    let partition_key_attribute_value = match KeyType::try_from(partition_key_name_and_field.1) {
+        Ok(key) => key.attribute_type_value(Ident::new("partition", struct_name.span())),
+        Err(e) => e.into_compile_error(),
+    };
+
+    quote! {
+        impl dynamodb_helper::DynamoItem for #struct_name {
+            type Key = #key_name;
+            type PartitionKey = #partition_key_type;
+            type ParseError = #parse_error;
+
+            const PARTITION_KEY_NAME: &'static str = #partition_key_name;
+            const RANGE_KEY_NAME: Option<&'static str> = #range_key_name;
+
+            fn to_item(self) -> Result<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>, dynamodb_helper::ValidationError> {
+                self.try_into()
+            }
+
+            fn try_from_item(item: &std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::ParseError> {
+                #struct_name::try_from(item)
+            }
+
+            fn partition_key_attribute_value(partition: Self::PartitionKey) -> aws_sdk_dynamodb::types::AttributeValue {
+                #partition_key_attribute_value
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::implementation::{value_from_hashmap_for_field, KeyType};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::Field;
+
+// a struct with the key fields of the item, which the generated methods accept instead of separate partition and range values
+// it is compared, hashed and displayed through its attribute values, so the key types only have to implement `Clone` and `Debug`
+pub fn key_struct(
+    struct_name: &Ident,
+    key_name: &Ident,
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> TokenStream {
+    let key_fields: Vec<&(String, &Field)> = std::iter::once(partition_key_name_and_field)
+        .chain(range_key_name_and_field)
+        .collect();
+    let idents: Vec<&Ident> = key_fields.iter().map(|(_, f)| f.ident.as_ref().unwrap()).collect();
+    let types = key_fields.iter().map(|(_, f)| &f.ty);
+    let attribute_values = key_fields.iter().map(|(name, f)| {
+        let ident = f.ident.as_ref().unwrap();
+        let attribute_value = attribute_value_for_key(f, ident);
+        quote!((#name, #attribute_value))
+    });
+
+    let from_values = match range_key_name_and_field {
+        Some((_, range)) => {
+            let partition_ident = &idents[0];
+            let partition_type = &partition_key_name_and_field.1.ty;
+            let range_ident = range.ident.as_ref().unwrap();
+            let range_type = &range.ty;
+            quote! {
+                impl From<(#partition_type, #range_type)> for #key_name {
+                    fn from((#partition_ident, #range_ident): (#partition_type, #range_type)) -> Self {
+                        #key_name { #partition_ident, #range_ident }
+                    }
+                }
+            }
+        }
+        None => {
+            let partition_ident = &idents[0];
+            let partition_type = &partition_key_name_and_field.1.ty;
+            quote! {
+                impl From<#partition_type> for #key_name {
+                    fn from(#partition_ident: #partition_type) -> Self {
+                        #key_name { #partition_ident }
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #[derive(Debug, Clone)]
+        pub struct #key_name {
+            #(pub #idents: #types,)*
+        }
+
+        impl #key_name {
+            // the key attributes in the order of the key schema
+            fn attribute_values(&self) -> Vec<(&'static str, aws_sdk_dynamodb::types::AttributeValue)> {
+                let #key_name { #(#idents),* } = self.clone();
+                vec![#(#attribute_values),*]
+            }
+        }
+
+        impl From<&#struct_name> for #key_name {
+            fn from(input: &#struct_name) -> Self {
+                #key_name {
+                    #(#idents: input.#idents.clone(),)*
+                }
+            }
+        }
+
+        #from_values
+
+        impl From<#key_name> for std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue> {
+            fn from(input: #key_name) -> Self {
+                input.attribute_values().into_iter().map(|(name, value)| (name.to_string(), value)).collect()
+            }
+        }
+
+        impl PartialEq for #key_name {
+            fn eq(&self, other: &Self) -> bool {
+                self.attribute_values() == other.attribute_values()
+            }
+        }
+
+        impl Eq for #key_name {}
+
+        impl std::hash::Hash for #key_name {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                self.attribute_values().iter().for_each(|(_, value)| dynamodb_helper::hash_key_attribute(value, state));
+            }
+        }
+
+        impl std::fmt::Display for #key_name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let parts: Vec<String> = self
+                    .attribute_values()
+                    .iter()
+                    .map(|(name, value)| format!("{}={}", name, dynamodb_helper::key_attribute_to_string(value)))
+                    .collect();
+                write!(f, "{}", parts.join(", "))
+            }
+        }
+    }
+}
+
+pub fn try_from_hashmap_to_key(
+    key_name: &Ident,
+    parse_error: &Ident,
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> TokenStream {
+    let retrievals = std::iter::once(partition_key_name_and_field)
+        .chain(range_key_name_and_field)
+        .map(|(name, f)| {
+            let ident = f.ident.as_ref().unwrap();
+            let value = value_from_hashmap_for_field(f, name, parse_error, false);
+            quote!(#ident: #value,)
+        });
+
+    quote! {
+        impl TryFrom<&std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #key_name {
+            type Error = #parse_error;
+
+            fn try_from(map: &std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                Ok(#key_name {
+                    #(#retrievals)*
+                })
+            }
+        }
+
+        impl TryFrom<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #key_name {
+            type Error = #parse_error;
+
+            fn try_from(map: std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #key_name::try_from(&map)
+            }
+        }
+    }
+}
+
+fn attribute_value_for_key(key_field: &Field, name_of_attribute: &Ident) -> TokenStream {
+    match KeyType::try_from(key_field) {
+        Ok(key) => key.attribute_type_value(name_of_attribute.clone()),
+        Err(e) => e.into_compile_error(),
+    }
+}
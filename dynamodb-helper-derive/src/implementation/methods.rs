@@ -0,0 +1,292 @@
+use proc_macro2::Ident;
+use quote::quote;
+use syn::Field;
+use crate::implementation::dynamo_types::KeyType;
+
+pub fn new_method(helper_ident: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        pub fn new(client: aws_sdk_dynamodb::Client, table: &str) -> Self {
+            #helper_ident {
+                client,
+                table: table.to_string()
+            }
+        }
+    }
+}
+
+pub fn build_method(helper_ident: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        pub async fn build(region: aws_sdk_dynamodb::config::Region, table: &str) -> Self {
+            let region_provider = aws_config::meta::region::RegionProviderChain::first_try(region).or_default_provider();
+            let shared_config = aws_config::from_env().region(region_provider).load().await;
+            #helper_ident {
+                client: aws_sdk_dynamodb::Client::new(&shared_config),
+                table: table.to_string(),
+            }
+        }
+    }
+}
+
+pub fn to_table_method(struct_name: &Ident) -> proc_macro2::TokenStream {
+    let table = table_for(struct_name);
+
+    quote! {
+        pub fn to_table(&self) -> dynamodb_helper::Table<#struct_name> {
+            #table
+        }
+    }
+}
+
+// the methods that delegate to the table create it themselves, so they still work when `to_table` is excluded
+fn table_for(struct_name: &Ident) -> proc_macro2::TokenStream {
+    quote!(dynamodb_helper::Table::<#struct_name>::new(self.client.clone(), &self.table))
+}
+
+pub fn put_method(struct_name: &Ident, put_error: &Ident) -> proc_macro2::TokenStream {
+    let table = table_for(struct_name);
+
+    quote! {
+        pub async fn put(&self, input: #struct_name) -> Result<aws_sdk_dynamodb::operation::put_item::PutItemOutput, #put_error> {
+            #table.put(input).await.map_err(Into::into)
+        }
+    }
+}
+
+pub fn delete_method(struct_name: &Ident, key_name: &Ident) -> proc_macro2::TokenStream {
+    let table = table_for(struct_name);
+
+    quote! {
+        pub async fn delete(&self, key: impl Into<#key_name>) -> Result<aws_sdk_dynamodb::operation::delete_item::DeleteItemOutput, aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::delete_item::DeleteItemError>> {
+            let key: #key_name = key.into();
+            #table.delete(key).await
+        }
+    }
+}
+
+pub fn get_methods(
+    struct_name: &Ident,
+    key_name: &Ident,
+    get_error: &Ident,
+    get_by_partition_error: &Ident,
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> proc_macro2::TokenStream {
+    let table = table_for(struct_name);
+    let get = quote! {
+        pub async fn get(&self, key: impl Into<#key_name>) -> Result<Option<#struct_name>, #get_error> {
+            let key: #key_name = key.into();
+            #table.get(key).await.map_err(Into::into)
+        }
+    };
+
+    if range_key_name_and_field.is_some() {
+        let partition_key_type = &partition_key_name_and_field.1.ty;
+
+        quote! {
+            pub async fn get_by_partition_key(&self, partition: #partition_key_type) -> Result<Vec<#struct_name>, #get_by_partition_error> {
+                #table.query(partition).await.map_err(Into::into)
+            }
+
+            #get
+        }
+    } else {
+        get
+    }
+}
+
+pub fn batch_get(struct_name: &Ident, key_name: &Ident, error: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        pub async fn batch_get(&self, keys: Vec<impl Into<#key_name>>) -> Result<Vec<#struct_name>, #error> {
+            let mapped_keys: Vec<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> = keys.into_iter()
+                .map(|key| Into::<#key_name>::into(key).into())
+                .collect();
+
+            let attrs = aws_sdk_dynamodb::types::KeysAndAttributes::builder()
+                .set_keys(Some(mapped_keys))
+                .build()
+                .expect("building keys and attributes to succeed");
+
+            let mut table_map = std::collections::HashMap::from([
+                (self.table.to_string(), attrs)
+            ]);
+
+            let result = &self.client.batch_get_item()
+                .set_request_items(Some(table_map))
+                .send()
+                .await?;
+
+            let mapped_result: Result<Vec<_>, _> = result.responses.as_ref().and_then(|v| v.get(self.table.as_str()))
+                .map(|v| v.iter()
+                    .map(|v| v.try_into())
+                    .collect())
+                .unwrap_or_else(|| Ok(vec![]));
+
+            let final_result = mapped_result?;
+
+            Ok(final_result)
+        }
+    }
+}
+
+pub fn batch_put_method(struct_name: &Ident, batch_put_error: &Ident) -> proc_macro2::TokenStream {
+    quote! {
+        pub async fn batch_put(&self, items: Vec<#struct_name>) -> Result<aws_sdk_dynamodb::operation::batch_write_item::BatchWriteItemOutput, #batch_put_error> {
+            let items_as_maps: Vec<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> = items.into_iter()
+                .map(|i| i.try_into())
+                .collect::<Result<_, dynamodb_helper::ValidationError>>()?;
+            items_as_maps.iter().try_for_each(dynamodb_helper::validate_item)?;
+
+            let requests: Vec<aws_sdk_dynamodb::types::WriteRequest> = items_as_maps.into_iter()
+                .map(|m| {
+                    aws_sdk_dynamodb::types::WriteRequest::builder()
+                        .put_request(aws_sdk_dynamodb::types::PutRequest::builder()
+                            .set_item(Some(m))
+                            .build()
+                            .expect("building put request to succeed")
+                        )
+                        .build()
+                })
+                .collect();
+
+            let mut requests_per_table = std::collections::HashMap::new();
+            requests_per_table.insert(self.table.to_string(), requests);
+
+            let result = self.client
+                .batch_write_item()
+                .set_request_items(Some(requests_per_table))
+                .send()
+                .await?;
+            Ok(result)
+        }
+    }
+}
+
+pub fn scan_method(struct_name: &Ident, error: &Ident) -> proc_macro2::TokenStream {
+    let table = table_for(struct_name);
+
+    quote! {
+        pub async fn scan(&self) -> Result<Vec<#struct_name>, #error> {
+            #table.scan().await.map_err(Into::into)
+        }
+    }
+}
+
+pub fn create_table_method(
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> proc_macro2::TokenStream {
+    let partition_key_name = &partition_key_name_and_field.0;
+    let partition_key_attribute_value = get_scalar_attribute(partition_key_name_and_field.1);
+
+    let ads_def = if let Some(range) = range_key_name_and_field {
+        let range_key_name = &range.0;
+        let range_key_attribute_value = get_scalar_attribute(range.1);
+
+        quote! {
+            let ads = vec![
+                aws_sdk_dynamodb::types::AttributeDefinition::builder()
+                    .attribute_name(#partition_key_name)
+                    .attribute_type(#partition_key_attribute_value)
+                    .build()
+                    .expect("building attribute definition to succeed"),
+                aws_sdk_dynamodb::types::AttributeDefinition::builder()
+                    .attribute_name(#range_key_name)
+                    .attribute_type(#range_key_attribute_value)
+                    .build()
+                    .expect("building attribute definition to succeed"),
+            ];
+        }
+    } else {
+        quote! {
+            let ads = vec![
+                aws_sdk_dynamodb::types::AttributeDefinition::builder()
+                    .attribute_name(#partition_key_name)
+                    .attribute_type(#partition_key_attribute_value)
+                    .build()
+                    .expect("building attribute definition to succeed"),
+            ];
+        }
+    };
+
+    let keys_def = if let Some(range) = range_key_name_and_field {
+        let range_key_name = &range.0;
+
+        quote! {
+            let keys = vec![
+                aws_sdk_dynamodb::types::KeySchemaElement::builder()
+                    .key_type(aws_sdk_dynamodb::types::KeyType::Hash)
+                    .attribute_name(#partition_key_name)
+                    .build()
+                    .expect("building keys and attributes to succeed"),
+                aws_sdk_dynamodb::types::KeySchemaElement::builder()
+                    .key_type(aws_sdk_dynamodb::types::KeyType::Range)
+                    .attribute_name(#range_key_name)
+                    .build()
+                    .expect("building keys and attributes to succeed"),
+            ];
+        }
+    } else {
+        quote! {
+            let keys = vec![
+                aws_sdk_dynamodb::types::KeySchemaElement::builder()
+                    .key_type(aws_sdk_dynamodb::types::KeyType::Hash)
+                    .attribute_name(#partition_key_name)
+                    .build()
+                    .expect("building key schema to succeed")
+            ];
+        }
+    };
+
+    quote! {
+        pub async fn create_table(&self) -> Result<aws_sdk_dynamodb::operation::create_table::CreateTableOutput, aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::create_table::CreateTableError>> {
+            #ads_def
+            #keys_def
+
+            self.client.create_table()
+                .table_name(&self.table)
+                .set_key_schema(Some(keys))
+                .set_attribute_definitions(Some(ads))
+                .billing_mode(aws_sdk_dynamodb::types::BillingMode::PayPerRequest)
+                .send()
+                .await
+        }
+
+        pub async fn create_table_with_provisioned_throughput(&self, read_capacity: i64, write_capacity: i64) -> Result<aws_sdk_dynamodb::operation::create_table::CreateTableOutput, aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::create_table::CreateTableError>> {
+            #ads_def
+            #keys_def
+
+            let provisioned = aws_sdk_dynamodb::types::ProvisionedThroughput::builder()
+                .read_capacity_units(read_capacity)
+                .write_capacity_units(write_capacity)
+                .build()
+                .expect("building provisioned throughput to succeed");
+
+            self.client.create_table()
+                .table_name(&self.table)
+                .set_key_schema(Some(keys))
+                .set_attribute_definitions(Some(ads))
+                .billing_mode(aws_sdk_dynamodb::types::BillingMode::Provisioned)
+                .provisioned_throughput(provisioned)
+                .send()
+                .await
+        }
+    }
+}
+
+pub fn delete_table_method() -> proc_macro2::TokenStream {
+    quote! {
+        pub async fn delete_table(&self) -> Result<aws_sdk_dynamodb::operation::delete_table::DeleteTableOutput, aws_sdk_dynamodb::error::SdkError<aws_sdk_dynamodb::operation::delete_table::DeleteTableError>> {
+            self.client.delete_table()
+                .table_name(&self.table)
+                .send()
+                .await
+        }
+    }
+}
+
+fn get_scalar_attribute(key_field: &Field) -> proc_macro2::TokenStream {
+    match KeyType::try_from(key_field) {
+        Ok(key) => key.scalar_attribute_type(),
+        Err(e) => e.into_compile_error(),
+    }
+}
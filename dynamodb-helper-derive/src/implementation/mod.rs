@@ -1,13 +1,25 @@
+mod attributes;
 mod constants;
 mod dynamo_types;
+mod enums;
 mod errors;
+mod item;
+mod keys;
 mod methods;
+mod newtypes;
+mod schema;
 mod traits;
 mod util;
 
+pub use attributes::*;
 pub use constants::*;
 pub use dynamo_types::*;
+pub use enums::*;
 pub use errors::*;
+pub use item::*;
+pub use keys::*;
 pub use methods::*;
+pub use newtypes::*;
+pub use schema::*;
 pub use traits::*;
 pub use util::*;
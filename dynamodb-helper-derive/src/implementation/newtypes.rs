@@ -0,0 +1,70 @@
+use crate::implementation::{
+    attribute_value_for, into_attribute_value_for, try_from_attribute_value_for, try_into_attribute_value_for, value_from_attribute_value,
+    KeyType, PossiblyOptionalDynamoType,
+};
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::{Error, Field};
+
+// a `#[dynamo(transparent)]` tuple struct with a single field is saved as its inner value
+// e.g. `struct OrderId(String)` is saved as a DynamoDB string
+pub fn newtype_conversions(struct_name: &Ident, error: &Ident, field: &Field) -> Result<TokenStream, Error> {
+    let dynamo_type = PossiblyOptionalDynamoType::try_from(field)?.into_iterable();
+    let name_as_string = struct_name.to_string();
+    let value = value_from_attribute_value(&dynamo_type, &name_as_string, error);
+    let try_from_attribute_value = try_from_attribute_value_for(struct_name, error);
+
+    // a newtype around a string, number or binary can serve as a key, which means its conversion cannot fail
+    // around other types it can, for example when the inner value is a struct with a `#[dynamo(serde)]` field
+    let into_attribute_value = match KeyType::try_from(field) {
+        Ok(KeyType::Custom(_) | KeyType::With(_)) | Err(_) => {
+            let attribute_value = attribute_value_for(&dynamo_type, quote!(input.0));
+            let try_into_attribute_value = try_into_attribute_value_for(struct_name, dynamo_type.attribute_type());
+            quote! {
+                impl TryFrom<#struct_name> for aws_sdk_dynamodb::types::AttributeValue {
+                    type Error = dynamodb_helper::ValidationError;
+
+                    fn try_from(input: #struct_name) -> Result<Self, Self::Error> {
+                        #attribute_value
+                    }
+                }
+
+                #try_into_attribute_value
+            }
+        }
+        Ok(key_type) => {
+            let attribute_value = key_type.attribute_type_value(Ident::new("inner", struct_name.span()));
+            let into_attribute_value = into_attribute_value_for(struct_name, dynamo_type.attribute_type());
+            let scalar_attribute_type = key_type.scalar_attribute_type();
+            quote! {
+                impl From<#struct_name> for aws_sdk_dynamodb::types::AttributeValue {
+                    fn from(input: #struct_name) -> Self {
+                        let inner = input.0;
+                        #attribute_value
+                    }
+                }
+
+                #into_attribute_value
+
+                impl dynamodb_helper::KeyAttribute for #struct_name {
+                    fn scalar_attribute_type() -> aws_sdk_dynamodb::types::ScalarAttributeType {
+                        #scalar_attribute_type
+                    }
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        impl TryFrom<&aws_sdk_dynamodb::types::AttributeValue> for #struct_name {
+            type Error = #error;
+
+            fn try_from(v: &aws_sdk_dynamodb::types::AttributeValue) -> Result<Self, Self::Error> {
+                (#value).map(#struct_name)
+            }
+        }
+
+        #into_attribute_value
+        #try_from_attribute_value
+    })
+}
@@ -0,0 +1,66 @@
+use crate::implementation::{get_attribute_name, FieldAttributes, KeyType, RenameRule, SCHEMA_CONSTANT_NAME};
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::Field;
+
+// a constant with the attribute name of every saved field, e.g. `Order::AN_ID`, for the expressions you write yourself
+// the attributes of a flattened field are constants of its own struct
+pub fn attribute_name_constants(struct_name: &Ident, fields: &Punctuated<Field, Comma>, rename_all: Option<RenameRule>) -> TokenStream {
+    let constants = fields
+        .iter()
+        .filter(|f| !FieldAttributes::try_from(&f.attrs).is_ok_and(|attributes| attributes.skip || attributes.extra || attributes.flatten))
+        .map(|f| {
+            let constant = attribute_name_constant(f);
+            let name = get_attribute_name(f, rename_all);
+            quote!(pub const #constant: &'static str = #name;)
+        });
+
+    quote! {
+        impl #struct_name {
+            #(#constants)*
+        }
+    }
+}
+
+pub fn attribute_name_constant(f: &Field) -> Ident {
+    format_ident!("{}", f.ident.as_ref().unwrap().unraw().to_string().to_uppercase())
+}
+
+// describes the keys and attributes of the table, the keys have the same types as those of `create_table`
+pub fn schema_for_struct(
+    struct_name: &Ident,
+    partition_key_name_and_field: &(String, &Field),
+    range_key_name_and_field: Option<&(String, &Field)>,
+) -> TokenStream {
+    let schema = format_ident!("{}", SCHEMA_CONSTANT_NAME);
+    let partition_key = key_schema(partition_key_name_and_field);
+    let range_key = match range_key_name_and_field {
+        Some(range) => {
+            let range_key = key_schema(range);
+            quote!(Some(#range_key))
+        }
+        None => quote!(None),
+    };
+
+    quote! {
+        impl #struct_name {
+            pub const #schema: dynamodb_helper::TableSchema = dynamodb_helper::TableSchema {
+                partition_key: #partition_key,
+                range_key: #range_key,
+                attributes: <#struct_name as dynamodb_helper::Flatten>::ATTRIBUTES,
+            };
+        }
+    }
+}
+
+fn key_schema(key_name_and_field: &(String, &Field)) -> TokenStream {
+    let name = &key_name_and_field.0;
+    let scalar_attribute_type = match KeyType::try_from(key_name_and_field.1) {
+        Ok(key) => key.scalar_attribute_type(),
+        Err(e) => e.into_compile_error(),
+    };
+    quote!(dynamodb_helper::KeySchema::new(#name, || #scalar_attribute_type))
+}
@@ -0,0 +1,529 @@
+use crate::{get_relevant_field_info, FieldAttributes, FieldDefault, IterableDynamoType, StructAttributes};
+use proc_macro2::Ident;
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::spanned::Spanned;
+use syn::{Error, Field};
+use crate::implementation::dynamo_types::DynamoType;
+use crate::implementation::PossiblyOptionalDynamoType;
+
+pub fn try_from_hashmap_to_struct(
+    struct_name: &Ident,
+    error: &Ident,
+    fields: &Punctuated<Field, Comma>,
+    struct_attributes: &StructAttributes,
+) -> TokenStream {
+    let struct_inserts = fields.iter().map(|f| try_from_hashmap_for_individual_field(struct_name, f, error, struct_attributes));
+    let unknown_attributes_check = if struct_attributes.deny_unknown_attributes {
+        quote! {
+            let mut unknown_attributes: Vec<&str> = map
+                .keys()
+                .map(|k| k.as_str())
+                .filter(|k| !<#struct_name as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES.contains(k))
+                .collect();
+            if !unknown_attributes.is_empty() {
+                unknown_attributes.sort();
+                return Err(#error::new(format!("Found unknown attributes {}", unknown_attributes.join(", "))));
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl TryFrom<std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #struct_name {
+            type Error = #error;
+
+            fn try_from(map: std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #struct_name::try_from(&map)
+            }
+        }
+        impl TryFrom<&std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>> for #struct_name {
+            type Error = #error;
+
+            fn try_from(map: &std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>) -> Result<Self, Self::Error> {
+                #unknown_attributes_check
+                Ok(#struct_name {
+                    #(#struct_inserts)*
+                })
+            }
+        }
+    }
+}
+
+fn try_from_hashmap_for_individual_field(struct_name: &Ident, f: &Field, err: &Ident, struct_attributes: &StructAttributes) -> TokenStream {
+    let (name, name_as_string, _) = get_relevant_field_info(f, struct_attributes.rename_all);
+    let value = if is_extra(f) {
+        quote! {
+            map.iter()
+                .filter(|(k, _)| !<#struct_name as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES.contains(&k.as_str()))
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        }
+    } else {
+        value_from_hashmap_for_field(f, &name_as_string, err, struct_attributes.default)
+    };
+
+    quote! {
+        #name: #value,
+    }
+}
+
+// builds an expression that gets the attribute with the given name out of `map` and converts it for the given field
+// a missing attribute gets the default of the field when it has one, or of its type when `default` is set for the whole struct
+pub fn value_from_hashmap_for_field(f: &Field, name_as_string: &str, err: &Ident, default: bool) -> TokenStream {
+    let attributes = match FieldAttributes::try_from(&f.attrs) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.into_compile_error();
+        }
+    };
+    if attributes.extra {
+        return extra_outside_struct_error(f);
+    }
+    if attributes.skip {
+        return match attributes.default {
+            Some(FieldDefault::Function(path)) => quote!(#path()),
+            _ => quote!(Default::default()),
+        };
+    }
+    // a flattened struct is read from the same map as the struct that contains it
+    if attributes.flatten {
+        let ty = &f.ty;
+        return quote! {
+            <#ty as TryFrom<&std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue>>>::try_from(map).map_err(|e| #err::new(format!("Could not convert {}: {}", #name_as_string, e)))?
+        };
+    }
+    let possibly_optional_dynamo_type = match PossiblyOptionalDynamoType::try_from(f) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.into_compile_error();
+        }
+    };
+    let default = match attributes.default {
+        Some(FieldDefault::Function(path)) => Some(quote!(#path)),
+        Some(FieldDefault::Trait) => Some(quote!(Default::default)),
+        // an attribute that is left out on purpose has to be readable again
+        None if default || attributes.skip_serializing_if.is_some() => Some(quote!(Default::default)),
+        None => None,
+    };
+
+    match (possibly_optional_dynamo_type, default) {
+        // an attribute that another service saved as a DynamoDB null is also None
+        (PossiblyOptionalDynamoType::Optional(v), None) => {
+            let conversion = value_from_attribute_value(&v, name_as_string, err);
+
+            quote! {
+                map.get(#name_as_string).filter(|v| !v.is_null()).map(|v| #conversion).transpose()?
+            }
+        }
+        // only a missing attribute gets the default, a null (like one saved with `store_null`) stays None
+        (PossiblyOptionalDynamoType::Optional(v), Some(default)) => {
+            let conversion = value_from_attribute_value(&v, name_as_string, err);
+
+            quote! {
+                match map.get(#name_as_string) {
+                    None => #default(),
+                    Some(v) if v.is_null() => None,
+                    Some(v) => (#conversion).map(Some)?,
+                }
+            }
+        }
+        // empty sets are not saved, so a missing set is an empty one
+        (PossiblyOptionalDynamoType::Normal(v @ IterableDynamoType::Set(_)), None) => {
+            let conversion = value_from_attribute_value(&v, name_as_string, err);
+
+            quote! {
+                map.get(#name_as_string).map(|v| #conversion).transpose()?.unwrap_or_default()
+            }
+        }
+        (PossiblyOptionalDynamoType::Normal(v), Some(default)) => {
+            let conversion = value_from_attribute_value(&v, name_as_string, err);
+
+            quote! {
+                map.get(#name_as_string).map(|v| #conversion).transpose()?.unwrap_or_else(#default)
+            }
+        }
+        (PossiblyOptionalDynamoType::Normal(v), None) => {
+            let conversion = value_from_attribute_value(&v, name_as_string, err);
+
+            quote! {
+                map.get(#name_as_string).ok_or_else(|| #err::new(format!("Did not find required attribute {}", #name_as_string))).and_then(|v| #conversion)?
+            }
+        }
+    }
+}
+
+// builds an expression that turns the attribute value `v` (a reference) into a Result of the Rust value
+pub fn value_from_attribute_value(val: &IterableDynamoType, name_as_string: &str, err: &Ident) -> TokenStream {
+    match val {
+        IterableDynamoType::Simple(simp) => match simp {
+            DynamoType::String => {
+                quote!(v.as_s().map(|v| v.to_string()).map_err(|_| #err::new(format!("Could not convert {} from Dynamo String", #name_as_string))))
+            }
+            DynamoType::Number => {
+                quote!(v.as_n().map_err(|_| #err::new(format!("Could not convert {} from Dynamo Number", #name_as_string))).and_then(|v| str::parse(v).map_err(|_| #err::new(format!("Could not parse number for {}", #name_as_string)))))
+            }
+            DynamoType::Boolean => {
+                quote!(v.as_bool().map(|v| *v).map_err(|_| #err::new(format!("Could not convert {} from Dynamo Boolean", #name_as_string))))
+            }
+            DynamoType::Binary(binary) => {
+                let blob_to_value = binary.blob_to_value(name_as_string, err);
+                quote!(v.as_b().map_err(|_| #err::new(format!("Could not convert {} from Dynamo Binary", #name_as_string))).and_then(|v| #blob_to_value))
+            }
+            DynamoType::Nested(ty) => {
+                quote!(<#ty as dynamodb_helper::TryFromAttributeValue>::try_from_attribute_value(v).map_err(|e| #err::new(format!("Could not convert {}: {}", #name_as_string, e))))
+            }
+            DynamoType::With(path) => {
+                quote!(#path::try_from_attribute_value(v).map_err(|e| #err::new(format!("Could not convert {}: {}", #name_as_string, e))))
+            }
+        },
+        IterableDynamoType::Optional(inner) => {
+            let inner_conversion = value_from_attribute_value(inner, name_as_string, err);
+            quote!(if v.is_null() { Ok(None) } else { (#inner_conversion).map(Some) })
+        }
+        IterableDynamoType::List(inner) => {
+            let inner_conversion = value_from_attribute_value(inner, name_as_string, err);
+            quote!(v.as_l().map_err(|_| #err::new(format!("Could not convert {} from Dynamo List", #name_as_string))).and_then(|v| v.iter().map(|v| #inner_conversion).collect::<Result<_, _>>()))
+        }
+        IterableDynamoType::Array(inner, len) => {
+            let inner_conversion = value_from_attribute_value(inner, name_as_string, err);
+            quote!(v.as_l().map_err(|_| #err::new(format!("Could not convert {} from Dynamo List", #name_as_string))).and_then(|v| v.iter().map(|v| #inner_conversion).collect::<Result<Vec<_>, _>>()).and_then(|v| <[_; #len]>::try_from(v).map_err(|v| #err::new(format!("Expected {} to have {} elements, found {}", #name_as_string, #len, v.len())))))
+        }
+        IterableDynamoType::Map(inner) => {
+            let inner_conversion = value_from_attribute_value(inner, name_as_string, err);
+            quote!(v.as_m().map_err(|_| #err::new(format!("Could not convert {} from Dynamo Map", #name_as_string))).and_then(|v| v.iter().map(|(k, v)| (#inner_conversion).map(|v| (k.to_string(), v))).collect::<Result<_, _>>()))
+        }
+        IterableDynamoType::Wrapped(wrapper, inner) => {
+            let inner_conversion = value_from_attribute_value(inner, name_as_string, err);
+            let wrap = wrapper.wrap();
+            quote!((#inner_conversion).map(#wrap))
+        }
+        IterableDynamoType::Set(simp) => match simp {
+            DynamoType::Binary(binary) => {
+                let blob_to_value = binary.blob_to_value(name_as_string, err);
+                quote!(v.as_bs().map_err(|_| #err::new(format!("Could not convert {} from Dynamo Binary Set", #name_as_string))).and_then(|v| v.iter().map(|v| #blob_to_value).collect::<Result<_, _>>()))
+            }
+            DynamoType::Number => {
+                quote!(v.as_ns().map_err(|_| #err::new(format!("Could not convert {} from Dynamo Number Set", #name_as_string))).and_then(|v| v.iter().map(|v| str::parse(v).map_err(|_| #err::new(format!("Could not parse number for {}", #name_as_string)))).collect::<Result<_, _>>()))
+            }
+            _ => {
+                quote!(v.as_ss().map_err(|_| #err::new(format!("Could not convert {} from Dynamo String Set", #name_as_string))).map(|v| v.iter().map(|v| v.to_string()).collect()))
+            }
+        },
+    }
+}
+
+// the extra attributes go in first, so they can never overwrite the value of a field
+// fails when a field cannot be converted, like a `#[dynamo(serde)]` field that serde cannot serialize
+pub fn from_struct_for_hashmap(struct_name: &Ident, fields: &Punctuated<Field, Comma>, struct_attributes: &StructAttributes) -> TokenStream {
+    let extra_inserts = fields.iter().filter(|f| is_extra(f)).map(|f| {
+        let name = &f.ident;
+        quote!(map.extend(input.#name);)
+    });
+    let hashmap_inserts = fields.iter().filter(|f| !is_extra(f)).map(|f| {
+        let (name, name_as_string, _) = get_relevant_field_info(f, struct_attributes.rename_all);
+        insert_into_hashmap_for_field(f, &name_as_string, quote!(input.#name), struct_attributes.store_null)
+    });
+
+    quote! {
+        impl TryFrom<#struct_name> for std::collections::HashMap<String, aws_sdk_dynamodb::types::AttributeValue> {
+            type Error = dynamodb_helper::ValidationError;
+
+            fn try_from(input: #struct_name) -> Result<Self, Self::Error> {
+                let mut map = std::collections::HashMap::new();
+                #(#extra_inserts)*
+                #(#hashmap_inserts)*
+                Ok(map)
+            }
+        }
+    }
+}
+
+// lists the names (and types) of the attributes of a struct, so it can be flattened into another one
+// collisions with the attributes of flattened fields are only known once those names are, hence the check at compile time
+pub fn flatten_for_struct(struct_name: &Ident, fields: &Punctuated<Field, Comma>, struct_attributes: &StructAttributes) -> TokenStream {
+    let mut own_names = vec![];
+    let mut own_types = vec![];
+    let mut flattened_types = vec![];
+    let mut has_extra = false;
+    for f in fields {
+        match FieldAttributes::try_from(&f.attrs) {
+            Ok(attributes) if attributes.extra => has_extra = true,
+            Ok(attributes) if attributes.skip => {}
+            Ok(attributes) if attributes.flatten => flattened_types.push(&f.ty),
+            _ => {
+                own_names.push(get_relevant_field_info(f, struct_attributes.rename_all).1);
+                own_types.push(match PossiblyOptionalDynamoType::try_from(f) {
+                    Ok(dynamo_type) => dynamo_type.into_iterable().attribute_type(),
+                    Err(e) => e.into_compile_error(),
+                });
+            }
+        }
+    }
+    let number_of_own_names = own_names.len();
+
+    // the extra field of a flattened struct only knows about the attributes of its own struct, so it would take those of this one
+    let extra_checks = flattened_types.iter().map(|ty| {
+        let message = format!(
+            "{} has a `#[dynamo(extra)]` field and cannot be flattened into {struct_name}",
+            quote!(#ty).to_string().replace(' ', "")
+        );
+        quote_spanned! {ty.span()=>
+            const _: () = assert!(!<#ty as dynamodb_helper::Flatten>::HAS_EXTRA, #message);
+        }
+    });
+
+    let collision_check = if flattened_types.is_empty() {
+        quote!()
+    } else {
+        let message = format!("The attributes of a flattened field of {struct_name} collide with other attributes");
+        quote! {
+            const _: () = assert!(
+                !dynamodb_helper::has_duplicate_attribute_names(<#struct_name as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES),
+                #message
+            );
+        }
+    };
+
+    quote! {
+        impl dynamodb_helper::Flatten for #struct_name {
+            const ATTRIBUTE_NAMES: &'static [&'static str] = &dynamodb_helper::concat_attribute_names::<{ #number_of_own_names #(+ <#flattened_types as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES.len())* }>(&[
+                &[#(#own_names),*],
+                #(<#flattened_types as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES),*
+            ]);
+            const ATTRIBUTES: &'static [dynamodb_helper::AttributeSchema] = &dynamodb_helper::concat_attribute_schemas::<{ #number_of_own_names #(+ <#flattened_types as dynamodb_helper::Flatten>::ATTRIBUTES.len())* }>(&[
+                &[#(dynamodb_helper::AttributeSchema { name: #own_names, attribute_type: #own_types }),*],
+                #(<#flattened_types as dynamodb_helper::Flatten>::ATTRIBUTES),*
+            ]);
+            const HAS_EXTRA: bool = #has_extra;
+        }
+
+        #collision_check
+        #(#extra_checks)*
+    }
+}
+
+fn is_extra(f: &Field) -> bool {
+    FieldAttributes::try_from(&f.attrs).is_ok_and(|attributes| attributes.extra)
+}
+
+fn extra_outside_struct_error(f: &Field) -> TokenStream {
+    Error::new(f.span(), "`#[dynamo(extra)]` can only be used on a field of a struct").into_compile_error()
+}
+
+// builds statements that convert the given value of a field and insert it into `map` under the given name
+// a None is not saved, unless the field or struct asks to store nulls
+// a conversion that fails returns a `ValidationError` with the name, so the statements belong in a function that returns one
+pub fn insert_into_hashmap_for_field(f: &Field, name_as_string: &str, value: TokenStream, store_null: bool) -> TokenStream {
+    let attributes = match FieldAttributes::try_from(&f.attrs) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.into_compile_error();
+        }
+    };
+    if attributes.extra {
+        return extra_outside_struct_error(f);
+    }
+    if attributes.skip {
+        return quote! {
+            let _ = #value;
+        };
+    }
+    if attributes.flatten {
+        return quote! {
+            map.extend(std::collections::HashMap::<String, aws_sdk_dynamodb::types::AttributeValue>::try_from(#value)?);
+        };
+    }
+    let possibly_optional_dynamo_type = match PossiblyOptionalDynamoType::try_from(f) {
+        Ok(v) => v,
+        Err(e) => {
+            return e.into_compile_error();
+        }
+    };
+    let store_null = store_null || attributes.store_null;
+
+    match attributes.skip_serializing_if {
+        Some(skip_if) => {
+            let insert = insert_for(possibly_optional_dynamo_type, name_as_string, quote!(value_to_check), store_null);
+            quote! {
+                let value_to_check = #value;
+                if !#skip_if(&value_to_check) {
+                    #insert
+                }
+            }
+        }
+        None => insert_for(possibly_optional_dynamo_type, name_as_string, value, store_null),
+    }
+}
+
+fn insert_for(
+    possibly_optional_dynamo_type: PossiblyOptionalDynamoType,
+    name_as_string: &str,
+    value: TokenStream,
+    store_null: bool,
+) -> TokenStream {
+    match possibly_optional_dynamo_type {
+        PossiblyOptionalDynamoType::Optional(v) if store_null => {
+            let map_insert = map_insert_for(&v, name_as_string);
+            quote! {
+                if let Some(to_insert) = #value {
+                    #map_insert
+                } else {
+                    map.insert(#name_as_string.to_string(), aws_sdk_dynamodb::types::AttributeValue::Null(true));
+                }
+            }
+        }
+        PossiblyOptionalDynamoType::Optional(v) => {
+            let map_insert = map_insert_for(&v, name_as_string);
+            quote! {
+                if let Some(to_insert) = #value {
+                    #map_insert
+                }
+            }
+        }
+        PossiblyOptionalDynamoType::Normal(v) => {
+            let map_insert = map_insert_for(&v, name_as_string);
+            quote! {
+                let to_insert = #value;
+                #map_insert
+            }
+        }
+    }
+}
+
+fn map_insert_for(val: &IterableDynamoType, name_as_string: &str) -> TokenStream {
+    let attribute_value = attribute_value_for(val, quote!(to_insert));
+
+    match val {
+        // DynamoDB does not accept empty sets
+        IterableDynamoType::Set(_) => quote! {
+            if !to_insert.is_empty() {
+                map.insert(#name_as_string.to_string(), (#attribute_value).map_err(|e| e.within(#name_as_string))?);
+            }
+        },
+        _ => quote! {
+            map.insert(#name_as_string.to_string(), (#attribute_value).map_err(|e| e.within(#name_as_string))?);
+        },
+    }
+}
+
+// builds an expression that turns the given (owned) value into a Result with the attribute value
+// only the conversions of nested types and `with` modules can fail, their error does not know the name of the attribute yet
+pub fn attribute_value_for(val: &IterableDynamoType, value: TokenStream) -> TokenStream {
+    let ok = |attribute_value: TokenStream| quote!(Ok::<_, dynamodb_helper::ValidationError>(#attribute_value));
+
+    match val {
+        IterableDynamoType::Simple(simp) => match simp {
+            DynamoType::String => ok(quote!(aws_sdk_dynamodb::types::AttributeValue::S(#value))),
+            DynamoType::Number => ok(quote!(aws_sdk_dynamodb::types::AttributeValue::N(#value.to_string()))),
+            DynamoType::Boolean => ok(quote!(aws_sdk_dynamodb::types::AttributeValue::Bool(#value))),
+            DynamoType::Binary(binary) => {
+                let blob = binary.value_to_blob(value);
+                ok(quote!(aws_sdk_dynamodb::types::AttributeValue::B(#blob)))
+            }
+            DynamoType::Nested(_) => quote!(dynamodb_helper::TryIntoAttributeValue::try_into_attribute_value(#value)),
+            DynamoType::With(path) => quote!(dynamodb_helper::IntoAttributeValueResult::into_attribute_value_result(#path::into_attribute_value(#value))),
+        },
+        IterableDynamoType::Optional(inner) => {
+            let inner_value = attribute_value_for(inner, quote!(v));
+            let null = ok(quote!(aws_sdk_dynamodb::types::AttributeValue::Null(true)));
+            quote! {
+                match #value {
+                    Some(v) => #inner_value,
+                    None => #null,
+                }
+            }
+        }
+        IterableDynamoType::List(inner) | IterableDynamoType::Array(inner, _) => {
+            let inner_value = attribute_value_for(inner, quote!(v));
+            quote!(#value.into_iter().map(|v| #inner_value).collect::<Result<_, _>>().map(aws_sdk_dynamodb::types::AttributeValue::L))
+        }
+        IterableDynamoType::Map(inner) => {
+            let inner_value = attribute_value_for(inner, quote!(v));
+            quote!(#value.into_iter().map(|(k, v)| (#inner_value).map(|v| (k, v))).collect::<Result<_, _>>().map(aws_sdk_dynamodb::types::AttributeValue::M))
+        }
+        IterableDynamoType::Wrapped(wrapper, inner) => attribute_value_for(inner, wrapper.unwrap(value)),
+        IterableDynamoType::Set(simp) => match simp {
+            DynamoType::Binary(binary) => {
+                let blob = binary.value_to_blob(quote!(v));
+                ok(quote!(aws_sdk_dynamodb::types::AttributeValue::Bs(#value.into_iter().map(|v| #blob).collect())))
+            }
+            DynamoType::Number => ok(quote!(aws_sdk_dynamodb::types::AttributeValue::Ns(#value.into_iter().map(|v| v.to_string()).collect()))),
+            _ => ok(quote!(aws_sdk_dynamodb::types::AttributeValue::Ss(#value.into_iter().collect()))),
+        },
+    }
+}
+
+// allows using the struct as a field of another struct, saved as a DynamoDB map
+pub fn from_struct_for_attribute_value(struct_name: &Ident) -> TokenStream {
+    let try_into_attribute_value = try_into_attribute_value_for(struct_name, quote!(Some(dynamodb_helper::AttributeType::Map)));
+
+    quote! {
+        impl TryFrom<#struct_name> for aws_sdk_dynamodb::types::AttributeValue {
+            type Error = dynamodb_helper::ValidationError;
+
+            fn try_from(input: #struct_name) -> Result<Self, Self::Error> {
+                std::collections::HashMap::try_from(input).map(aws_sdk_dynamodb::types::AttributeValue::M)
+            }
+        }
+
+        #try_into_attribute_value
+    }
+}
+
+pub fn try_from_attribute_value_to_struct(struct_name: &Ident, error: &Ident) -> TokenStream {
+    let try_from_attribute_value = try_from_attribute_value_for(struct_name, error);
+
+    quote! {
+        impl TryFrom<&aws_sdk_dynamodb::types::AttributeValue> for #struct_name {
+            type Error = #error;
+
+            fn try_from(value: &aws_sdk_dynamodb::types::AttributeValue) -> Result<Self, Self::Error> {
+                value.as_m().map_err(|_| #error::new("Could not convert from Dynamo Map".to_string())).and_then(|v| v.try_into())
+            }
+        }
+
+        #try_from_attribute_value
+    }
+}
+
+// the traits the generated code uses for fields, based on the `From` implementation for attribute values
+pub fn into_attribute_value_for(name: &Ident, attribute_type: TokenStream) -> TokenStream {
+    quote! {
+        impl dynamodb_helper::IntoAttributeValue for #name {
+            const ATTRIBUTE_TYPE: Option<dynamodb_helper::AttributeType> = #attribute_type;
+
+            fn into_attribute_value(self) -> aws_sdk_dynamodb::types::AttributeValue {
+                self.into()
+            }
+        }
+    }
+}
+
+// for types whose conversion can fail, based on their `TryFrom` implementation for attribute values
+pub fn try_into_attribute_value_for(name: &Ident, attribute_type: TokenStream) -> TokenStream {
+    quote! {
+        impl dynamodb_helper::TryIntoAttributeValue for #name {
+            const ATTRIBUTE_TYPE: Option<dynamodb_helper::AttributeType> = #attribute_type;
+
+            fn try_into_attribute_value(self) -> Result<aws_sdk_dynamodb::types::AttributeValue, dynamodb_helper::ValidationError> {
+                self.try_into()
+            }
+        }
+    }
+}
+
+pub fn try_from_attribute_value_for(name: &Ident, error: &Ident) -> TokenStream {
+    quote! {
+        impl dynamodb_helper::TryFromAttributeValue for #name {
+            type Error = #error;
+
+            fn try_from_attribute_value(value: &aws_sdk_dynamodb::types::AttributeValue) -> Result<Self, Self::Error> {
+                #name::try_from(value)
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{BATCH_GET_METHOD_NAME, BATCH_PUT_METHOD_NAME, GET_METHOD_NAME, PUT_METHOD_NAME, SCAN_METHOD_NAME};
+use crate::{FieldAttributes, RenameRule};
 use proc_macro2::{Ident};
 use proc_macro2::TokenTree::Literal;
 use quote::quote;
@@ -8,14 +8,15 @@ use syn::token::Comma;
 use syn::{Attribute, Meta};
 use syn::Field;
 
-pub fn get_ident_and_type_of_field_annotated_with<'a>(
+pub fn get_attribute_name_and_field_annotated_with<'a>(
     fields: &'a Punctuated<Field, Comma>,
     name: &'a str,
-) -> Option<(&'a Ident, &'a syn::Type)> {
+    rename_all: Option<RenameRule>,
+) -> Option<(String, &'a Field)> {
     fields
         .iter()
         .filter(|f| get_attribute(f, name).is_some())
-        .map(|f| (f.ident.as_ref().unwrap(), &f.ty))
+        .map(|f| (get_attribute_name(f, rename_all), f))
         .next()
 }
 
@@ -25,25 +26,52 @@ fn get_attribute<'a>(f: &'a Field, name: &'a str) -> Option<&'a Attribute> {
         .find(|&attr| attr.path().segments.len() == 1 && attr.path().segments[0].ident == name)
 }
 
-pub fn get_relevant_field_info(f: &Field) -> (&Ident, String, &syn::Type) {
+// the field, the name of its attribute and its type
+pub fn get_relevant_field_info(f: &Field, rename_all: Option<RenameRule>) -> (&Ident, String, &syn::Type) {
     let name = &f.ident.as_ref().unwrap();
-    let name_as_string = name.to_string();
+    let name_as_string = get_attribute_name(f, rename_all);
     let field_type = &f.ty;
     (name, name_as_string, field_type)
 }
 
+// an invalid `#[dynamo(...)]` on the field is reported when converting it, so it falls back to the field name here
+pub fn get_attribute_name(f: &Field, rename_all: Option<RenameRule>) -> String {
+    let field_name = f.ident.as_ref().unwrap().to_string();
+    match FieldAttributes::try_from(&f.attrs).ok().and_then(|attributes| attributes.rename) {
+        Some(rename) => rename,
+        None => rename_all.map(|rule| rule.apply(&field_name)).unwrap_or(field_name),
+    }
+}
+
 pub fn matches_any_type(ty: &syn::Type, type_names: Vec<&str>) -> bool {
     type_names.iter().any(|v| matches_type(ty, v))
 }
 
+// looks at the last segment of the path, so `std::string::String` matches `String` as well
 pub fn matches_type(ty: &syn::Type, type_name: &str) -> bool {
     if let syn::Type::Path(ref p) = ty {
-        let first_match = p.path.segments[0].ident.to_string();
-        return first_match == type_name;
+        return p.path.segments.last().is_some_and(|last| last.ident == type_name);
     }
     false
 }
 
+// the generic arguments of a type, e.g. the `String` in `Vec<String>` or `std::vec::Vec<String>`
+pub fn get_inner_types(ty: &syn::Type) -> Vec<&syn::Type> {
+    if let syn::Type::Path(ref p) = ty {
+        if let Some(syn::PathArguments::AngleBracketed(ref args)) = p.path.segments.last().map(|last| &last.arguments) {
+            return args
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    syn::GenericArgument::Type(t) => Some(t),
+                    _ => None,
+                })
+                .collect();
+        }
+    }
+    vec![]
+}
+
 pub fn get_macro_attribute(attrs: &[Attribute], attribute_name: &str) -> Vec<String> {
     attrs
         .iter()
@@ -64,18 +92,6 @@ pub fn get_macro_attribute(attrs: &[Attribute], attribute_name: &str) -> Vec<Str
         .collect()
 }
 
-pub fn tokenstream_or_empty_if_no_retrieval_methods(stream: TokenStream2, exclusions: &[&str]) -> TokenStream2 {
-    tokenstream_or_empty_if_boolean_function(stream, &|| {
-        exclusions.contains(&GET_METHOD_NAME) && exclusions.contains(&BATCH_GET_METHOD_NAME) && exclusions.contains(&SCAN_METHOD_NAME)
-    })
-}
-
-pub fn tokenstream_or_empty_if_no_put_methods(stream: TokenStream2, exclusions: &[&str]) -> TokenStream2 {
-    tokenstream_or_empty_if_boolean_function(stream, &|| {
-        exclusions.contains(&PUT_METHOD_NAME) && exclusions.contains(&BATCH_PUT_METHOD_NAME)
-    })
-}
-
 pub fn tokenstream_or_empty_if_exclusion(stream: TokenStream2, method_name: &str, exclusions: &[&str]) -> TokenStream2 {
     tokenstream_or_empty_if_boolean_function(stream, &|| exclusions.contains(&method_name))
 }
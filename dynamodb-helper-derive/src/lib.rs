@@ -0,0 +1,279 @@
+//! The `DynamoDb` derive macro. Use it through the `dynamodb-helper` crate, which also contains the traits the generated code relies on.
+
+extern crate core;
+
+mod implementation;
+
+use crate::implementation::*;
+use proc_macro::TokenStream;
+use proc_macro2::Ident;
+use quote::quote;
+use syn::Data::Enum;
+use syn::Data::Struct;
+use syn::Data::Union;
+use syn::DataStruct;
+use syn::Fields::{Named, Unnamed};
+use syn::{FieldsNamed, FieldsUnnamed};
+use syn::{parse_macro_input, DeriveInput, Error};
+
+#[proc_macro_derive(DynamoDb, attributes(partition, range, exclusion, dynamo))]
+pub fn create_dynamodb_helper(item: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(item as DeriveInput);
+    let name = ast.ident;
+    let helper_name = format!("{name}Db");
+    let helper_ident = Ident::new(&helper_name, name.span());
+    let key_ident = Ident::new(&format!("{name}Key"), name.span());
+
+    let fields = match ast.data {
+        Struct(DataStruct {
+            fields: Named(FieldsNamed { ref named, .. }),
+            ..
+        }) => named,
+        Struct(DataStruct {
+            fields: Unnamed(FieldsUnnamed { ref unnamed, .. }),
+            ..
+        }) => {
+            let struct_attributes = match StructAttributes::try_from(&ast.attrs) {
+                Ok(attributes) => attributes,
+                Err(e) => return e.into_compile_error().into(),
+            };
+
+            if !struct_attributes.transparent || unnamed.len() != 1 {
+                return Error::new(
+                    name.span(),
+                    "DynamoDB macro only supports tuple structs with a single field, marked with `#[dynamo(transparent)]`".to_string(),
+                )
+                .into_compile_error()
+                .into();
+            }
+
+            if let Err(e) = validate_field_types(unnamed) {
+                return e.into_compile_error().into();
+            }
+
+            let (_, _, _, _, parse_error) = generate_error_names(&helper_ident);
+            let conversions = match newtype_conversions(&name, &parse_error, &unnamed[0]) {
+                Ok(conversions) => conversions,
+                Err(e) => return e.into_compile_error().into(),
+            };
+            let parse_error_stream = generate_parse_error(&parse_error);
+
+            return quote! {
+                #conversions
+
+                #parse_error_stream
+            }
+            .into();
+        }
+        Enum(ref data) => {
+            let (_, _, _, _, parse_error) = generate_error_names(&helper_ident);
+
+            if let Some(e) = fields_of_variants(data)
+                .into_iter()
+                .filter_map(|fields| validate_field_types(fields).err())
+                .reduce(|mut acc, e| {
+                    acc.combine(e);
+                    acc
+                })
+            {
+                return e.into_compile_error().into();
+            }
+
+            let conversions = match enum_conversions(&name, &parse_error, data, &ast.attrs) {
+                Ok(conversions) => conversions,
+                Err(e) => return e.into_compile_error().into(),
+            };
+            let parse_error_stream = generate_parse_error(&parse_error);
+
+            return quote! {
+                #conversions
+
+                #parse_error_stream
+            }
+            .into();
+        }
+        Union(_) => {
+            return Error::new(
+                name.span(),
+                "DynamoDB macro cannot be used with a union - use a struct instead".to_string(),
+            )
+            .into_compile_error()
+            .into()
+        }
+        _ => {
+            return Error::new(
+                name.span(),
+                "DynamoDB macro can only be used with a struct with named fields".to_string(),
+            )
+            .into_compile_error()
+            .into()
+        }
+    };
+
+    let struct_attributes = match StructAttributes::try_from(&ast.attrs) {
+        Ok(attributes) => attributes,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
+    if struct_attributes.transparent {
+        return Error::new(
+            name.span(),
+            "`#[dynamo(transparent)]` can only be used on a tuple struct with a single field".to_string(),
+        )
+        .into_compile_error()
+        .into();
+    }
+
+    if let Err(e) = validate_field_types(fields).and_then(|_| validate_field_attributes(fields, &struct_attributes)) {
+        return e.into_compile_error().into();
+    }
+
+    if struct_attributes.nested {
+        let (_, _, _, _, parse_error) = generate_error_names(&helper_ident);
+        let from_struct_for_hashmap = from_struct_for_hashmap(&name, fields, &struct_attributes);
+        let try_from_hashmap_for_struct = try_from_hashmap_to_struct(&name, &parse_error, fields, &struct_attributes);
+        let flatten_for_struct = flatten_for_struct(&name, fields, &struct_attributes);
+        let attribute_name_constants = attribute_name_constants(&name, fields, struct_attributes.rename_all);
+        let from_struct_for_attribute_value = from_struct_for_attribute_value(&name);
+        let try_from_attribute_value_for_struct = try_from_attribute_value_to_struct(&name, &parse_error);
+        let parse_error_stream = generate_parse_error(&parse_error);
+
+        return quote! {
+            #from_struct_for_hashmap
+            #try_from_hashmap_for_struct
+            #flatten_for_struct
+            #attribute_name_constants
+            #from_struct_for_attribute_value
+            #try_from_attribute_value_for_struct
+
+            #parse_error_stream
+        }
+        .into();
+    }
+
+    let exclusion_list = get_macro_attribute(&ast.attrs, EXCLUSION_ATTRIBUTE_NAME);
+    let exclusion_list_refs: Vec<&str> = exclusion_list.iter().map(|x| &**x).collect();
+
+    let (get_error, get_by_partition_error, batch_get_error, scan_error, parse_error) = generate_error_names(&helper_ident);
+    let (put_error, batch_put_error) = generate_put_error_names(&helper_ident);
+    let errors = generate_helper_error(&helper_ident, &exclusion_list_refs);
+
+    let partition_key_name_and_field = match get_attribute_name_and_field_annotated_with(fields, PARTITION_KEY_ATTRIBUTE_NAME, struct_attributes.rename_all) {
+        Some(res) => res,
+        None => {
+            return Error::new(
+                name.span(),
+                "You need to define a partition key for your DynamoDB struct! Place the `#[partition]` attribute above the field that serves as your key.".to_string()
+            )
+            .into_compile_error()
+            .into();
+        }
+    };
+
+    let range_key_name_and_field = get_attribute_name_and_field_annotated_with(fields, RANGE_KEY_ATTRIBUTE_NAME, struct_attributes.rename_all);
+
+    let key_errors = std::iter::once(&partition_key_name_and_field)
+        .chain(range_key_name_and_field.as_ref())
+        .filter_map(|(_, key_field)| KeyType::try_from(*key_field).err())
+        .reduce(|mut acc, e| {
+            acc.combine(e);
+            acc
+        });
+    if let Some(e) = key_errors {
+        return e.into_compile_error().into();
+    }
+
+    let from_struct_for_hashmap = from_struct_for_hashmap(&name, fields, &struct_attributes);
+    let from_struct_for_attribute_value = from_struct_for_attribute_value(&name);
+
+    let try_from_hashmap_for_struct = try_from_hashmap_to_struct(&name, &parse_error, fields, &struct_attributes);
+    let flatten_for_struct = flatten_for_struct(&name, fields, &struct_attributes);
+    let attribute_name_constants = attribute_name_constants(&name, fields, struct_attributes.rename_all);
+    let schema_for_struct = schema_for_struct(&name, &partition_key_name_and_field, range_key_name_and_field.as_ref());
+    let try_from_attribute_value_for_struct = try_from_attribute_value_to_struct(&name, &parse_error);
+
+    let key_struct = key_struct(&name, &key_ident, &partition_key_name_and_field, range_key_name_and_field.as_ref());
+    let try_from_hashmap_for_key =
+        try_from_hashmap_to_key(&key_ident, &parse_error, &partition_key_name_and_field, range_key_name_and_field.as_ref());
+    let dynamo_item_for_struct = dynamo_item_for_struct(
+        &name,
+        &key_ident,
+        &parse_error,
+        &partition_key_name_and_field,
+        range_key_name_and_field.as_ref(),
+    );
+
+    let new = tokenstream_or_empty_if_exclusion(new_method(&helper_ident), NEW_METHOD_NAME, &exclusion_list_refs);
+
+    let build = tokenstream_or_empty_if_exclusion(build_method(&helper_ident), BUILD_METHOD_NAME, &exclusion_list_refs);
+    let to_table = tokenstream_or_empty_if_exclusion(to_table_method(&name), TO_TABLE_METHOD_NAME, &exclusion_list_refs);
+
+    let gets = tokenstream_or_empty_if_exclusion(
+        get_methods(
+            &name,
+            &key_ident,
+            &get_error,
+            &get_by_partition_error,
+            &partition_key_name_and_field,
+            range_key_name_and_field.as_ref(),
+        ),
+        GET_METHOD_NAME,
+        &exclusion_list_refs,
+    );
+
+    let batch_get = tokenstream_or_empty_if_exclusion(
+        batch_get(&name, &key_ident, &batch_get_error),
+        BATCH_GET_METHOD_NAME,
+        &exclusion_list_refs,
+    );
+
+    let create_table = tokenstream_or_empty_if_exclusion(
+        create_table_method(&partition_key_name_and_field, range_key_name_and_field.as_ref()),
+        CREATE_TABLE_METHOD_NAME,
+        &exclusion_list_refs,
+    );
+    let delete_table = tokenstream_or_empty_if_exclusion(delete_table_method(), DELETE_TABLE_METHOD_NAME, &exclusion_list_refs);
+    let put = tokenstream_or_empty_if_exclusion(put_method(&name, &put_error), PUT_METHOD_NAME, &exclusion_list_refs);
+    let batch_put = tokenstream_or_empty_if_exclusion(batch_put_method(&name, &batch_put_error), BATCH_PUT_METHOD_NAME, &exclusion_list_refs);
+    let delete = tokenstream_or_empty_if_exclusion(delete_method(&name, &key_ident), DELETE_METHOD_NAME, &exclusion_list_refs);
+    let scan = tokenstream_or_empty_if_exclusion(scan_method(&name, &scan_error), SCAN_METHOD_NAME, &exclusion_list_refs);
+
+    let public_version = quote! {
+        #from_struct_for_hashmap
+        #try_from_hashmap_for_struct
+        #flatten_for_struct
+        #attribute_name_constants
+        #schema_for_struct
+        #from_struct_for_attribute_value
+        #try_from_attribute_value_for_struct
+
+        #key_struct
+        #try_from_hashmap_for_key
+        #dynamo_item_for_struct
+
+        pub struct #helper_ident {
+            pub client: aws_sdk_dynamodb::Client,
+            pub table: String,
+        }
+
+        impl #helper_ident {
+            #new
+            #build
+            #to_table
+
+            #create_table
+            #delete_table
+
+            #put
+            #gets
+            #batch_get
+            #batch_put
+            #delete
+            #scan
+        }
+
+        #errors
+    };
+
+    public_version.into()
+}
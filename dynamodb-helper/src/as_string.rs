@@ -0,0 +1,29 @@
+//! Saves a value as a DynamoDB string, using its `Display` and `FromStr` implementations.
+//!
+//! This is the module the macro uses for `#[dynamo(as_string)]`, which works for types like `Uuid`, `IpAddr` or `Url`.
+
+use crate::AttributeType;
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use std::fmt::Display;
+use std::str::FromStr;
+
+pub const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::String);
+
+pub fn into_attribute_value<T: Display>(value: T) -> AttributeValue {
+    AttributeValue::S(value.to_string())
+}
+
+pub fn try_from_attribute_value<T>(value: &AttributeValue) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .as_s()
+        .map_err(|_| "expected a string".to_string())
+        .and_then(|v| v.parse().map_err(|e: T::Err| e.to_string()))
+}
+
+pub fn scalar_attribute_type() -> ScalarAttributeType {
+    ScalarAttributeType::S
+}
@@ -0,0 +1,95 @@
+use crate::{AttributeType, ValidationError};
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::fmt::Display;
+
+/// Converts a value into a DynamoDB attribute value.
+///
+/// The generated code calls this for every field type the macro does not know about.
+/// The macro implements it for structs, enums and newtypes that derive `DynamoDb`, you can implement it for your own types.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be saved as a DynamoDB attribute",
+    note = "derive `DynamoDb` for it, implement `IntoAttributeValue` or add `#[dynamo(with = \"...\")]` to the field"
+)]
+pub trait IntoAttributeValue {
+    /// The type the value is saved as, used for the `SCHEMA` of structs. `None` when it is not known up front.
+    const ATTRIBUTE_TYPE: Option<AttributeType> = None;
+
+    fn into_attribute_value(self) -> AttributeValue;
+}
+
+/// Converts a value into a DynamoDB attribute value, for values whose conversion can fail.
+///
+/// The generated code calls this for every field type the macro does not know about, and returns the error from the put methods.
+/// It is implemented for every [`IntoAttributeValue`]. The macro implements it for structs, enums with data and newtypes that derive `DynamoDb`,
+/// which fail when one of their fields cannot be converted, like a `#[dynamo(serde)]` field that serde cannot serialize.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be saved as a DynamoDB attribute",
+    note = "derive `DynamoDb` for it, implement `IntoAttributeValue` or add `#[dynamo(with = \"...\")]` to the field"
+)]
+pub trait TryIntoAttributeValue {
+    /// The type the value is saved as, used for the `SCHEMA` of structs. `None` when it is not known up front.
+    const ATTRIBUTE_TYPE: Option<AttributeType> = None;
+
+    fn try_into_attribute_value(self) -> Result<AttributeValue, ValidationError>;
+}
+
+impl<T: IntoAttributeValue> TryIntoAttributeValue for T {
+    const ATTRIBUTE_TYPE: Option<AttributeType> = <T as IntoAttributeValue>::ATTRIBUTE_TYPE;
+
+    fn try_into_attribute_value(self) -> Result<AttributeValue, ValidationError> {
+        Ok(self.into_attribute_value())
+    }
+}
+
+// lets the `into_attribute_value` function of a `with` module return either an attribute value or a result
+#[doc(hidden)]
+pub trait IntoAttributeValueResult {
+    fn into_attribute_value_result(self) -> Result<AttributeValue, ValidationError>;
+}
+
+impl IntoAttributeValueResult for AttributeValue {
+    fn into_attribute_value_result(self) -> Result<AttributeValue, ValidationError> {
+        Ok(self)
+    }
+}
+
+impl<E: Display> IntoAttributeValueResult for Result<AttributeValue, E> {
+    fn into_attribute_value_result(self) -> Result<AttributeValue, ValidationError> {
+        self.map_err(ValidationError::new)
+    }
+}
+
+/// Converts a DynamoDB attribute value back into a value, the counterpart of [`IntoAttributeValue`].
+///
+/// The error is added to the parse error of the struct that contains the field.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be read from a DynamoDB attribute",
+    note = "derive `DynamoDb` for it, implement `TryFromAttributeValue` or add `#[dynamo(with = \"...\")]` to the field"
+)]
+pub trait TryFromAttributeValue: Sized {
+    type Error: std::fmt::Display;
+
+    fn try_from_attribute_value(value: &AttributeValue) -> Result<Self, Self::Error>;
+}
+
+// a char is saved as a string of one character
+impl IntoAttributeValue for char {
+    const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::String);
+
+    fn into_attribute_value(self) -> AttributeValue {
+        AttributeValue::S(self.to_string())
+    }
+}
+
+impl TryFromAttributeValue for char {
+    type Error = String;
+
+    fn try_from_attribute_value(value: &AttributeValue) -> Result<Self, Self::Error> {
+        let string = value.as_s().map_err(|_| "expected a string".to_string())?;
+        let mut chars = string.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(format!("expected a single character, found '{string}'")),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{AttributeType, IntoAttributeValue, KeyAttribute, TryFromAttributeValue};
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+
+// decimals are saved as a DynamoDB number, without going through a float
+macro_rules! decimal_attribute {
+    ($ty:ty, $parse:expr) => {
+        impl IntoAttributeValue for $ty {
+            const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::Number);
+
+            fn into_attribute_value(self) -> AttributeValue {
+                AttributeValue::N(self.to_string())
+            }
+        }
+
+        impl TryFromAttributeValue for $ty {
+            type Error = String;
+
+            fn try_from_attribute_value(value: &AttributeValue) -> Result<Self, Self::Error> {
+                let number = value.as_n().map_err(|_| "expected a number".to_string())?;
+                $parse(number).map_err(|e| format!("could not parse decimal '{number}': {e}"))
+            }
+        }
+
+        impl KeyAttribute for $ty {
+            fn scalar_attribute_type() -> ScalarAttributeType {
+                ScalarAttributeType::N
+            }
+        }
+    };
+}
+
+// DynamoDB might return numbers in scientific notation, which `Decimal::from_str` does not accept
+#[cfg(feature = "rust_decimal")]
+decimal_attribute!(rust_decimal::Decimal, |number: &str| {
+    if number.contains(['e', 'E']) {
+        rust_decimal::Decimal::from_scientific(number)
+    } else {
+        number.parse::<rust_decimal::Decimal>()
+    }
+});
+
+#[cfg(feature = "bigdecimal")]
+decimal_attribute!(bigdecimal::BigDecimal, |number: &str| number.parse::<bigdecimal::BigDecimal>());
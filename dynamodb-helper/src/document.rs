@@ -0,0 +1,72 @@
+//! Saves any value that implements `Serialize` and `Deserialize` as a native DynamoDB document: maps, lists, strings, numbers, booleans and nulls.
+//!
+//! This is the module the macro uses for `#[dynamo(serde)]`. The value is converted through a `serde_json::Value`.
+
+use crate::AttributeType;
+use aws_sdk_dynamodb::types::AttributeValue;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
+
+/// Depends on the value, which can be any kind of document.
+pub const ATTRIBUTE_TYPE: Option<AttributeType> = None;
+
+/// Fails when the value cannot be serialized, for example because it is a map with keys that are not strings.
+pub fn into_attribute_value<T: Serialize>(value: T) -> Result<AttributeValue, String> {
+    serde_json::to_value(value).map(attribute_value_from_json).map_err(|e| e.to_string())
+}
+
+pub fn try_from_attribute_value<T: DeserializeOwned>(value: &AttributeValue) -> Result<T, String> {
+    json_from_attribute_value(value).and_then(|v| serde_json::from_value(v).map_err(|e| e.to_string()))
+}
+
+fn attribute_value_from_json(value: Value) -> AttributeValue {
+    match value {
+        Value::Null => AttributeValue::Null(true),
+        Value::Bool(b) => AttributeValue::Bool(b),
+        Value::Number(n) => AttributeValue::N(n.to_string()),
+        Value::String(s) => AttributeValue::S(s),
+        Value::Array(values) => AttributeValue::L(values.into_iter().map(attribute_value_from_json).collect()),
+        Value::Object(values) => AttributeValue::M(values.into_iter().map(|(k, v)| (k, attribute_value_from_json(v))).collect()),
+    }
+}
+
+// sets become arrays and binaries arrays of bytes, so they can be read into a Vec or HashSet
+fn json_from_attribute_value(value: &AttributeValue) -> Result<Value, String> {
+    match value {
+        AttributeValue::Null(_) => Ok(Value::Null),
+        AttributeValue::Bool(b) => Ok(Value::Bool(*b)),
+        AttributeValue::N(n) => number_from_string(n),
+        AttributeValue::S(s) => Ok(Value::String(s.to_string())),
+        AttributeValue::L(values) => values
+            .iter()
+            .map(json_from_attribute_value)
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        AttributeValue::M(values) => values
+            .iter()
+            .map(|(k, v)| json_from_attribute_value(v).map(|v| (k.to_string(), v)))
+            .collect::<Result<Map<_, _>, _>>()
+            .map(Value::Object),
+        AttributeValue::Ss(values) => Ok(Value::Array(values.iter().map(|v| Value::String(v.to_string())).collect())),
+        AttributeValue::Ns(values) => values
+            .iter()
+            .map(|v| number_from_string(v))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        AttributeValue::B(blob) => Ok(bytes_to_json(blob.as_ref())),
+        AttributeValue::Bs(blobs) => Ok(Value::Array(blobs.iter().map(|v| bytes_to_json(v.as_ref())).collect())),
+        _ => Err("unknown DynamoDB attribute type".to_string()),
+    }
+}
+
+fn number_from_string(value: &str) -> Result<Value, String> {
+    value
+        .parse::<Number>()
+        .map(Value::Number)
+        .map_err(|_| format!("could not parse number '{value}'"))
+}
+
+fn bytes_to_json(bytes: &[u8]) -> Value {
+    Value::Array(bytes.iter().map(|b| Value::Number((*b).into())).collect())
+}
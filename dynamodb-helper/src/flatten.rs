@@ -0,0 +1,77 @@
+use crate::AttributeSchema;
+
+/// Implemented by structs that derive `DynamoDb`, so they can be flattened into another struct with `#[dynamo(flatten)]`.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be flattened into a DynamoDB item",
+    note = "only structs that derive `DynamoDb` can be used with `#[dynamo(flatten)]`"
+)]
+pub trait Flatten {
+    /// The names of the attributes the struct is saved with, including those of its own flattened fields.
+    const ATTRIBUTE_NAMES: &'static [&'static str];
+
+    /// The same attributes, together with their types.
+    const ATTRIBUTES: &'static [AttributeSchema];
+
+    /// Whether the struct has a `#[dynamo(extra)]` field. Such a field would collect the attributes of the struct it is flattened into,
+    /// so flattening it results in a compile error.
+    const HAS_EXTRA: bool = false;
+}
+
+// used by the macro to combine the attribute names (and schemas) of a struct with those of its flattened fields
+#[doc(hidden)]
+pub const fn concat_attribute_names<const N: usize>(parts: &[&[&'static str]]) -> [&'static str; N] {
+    concat(parts, "")
+}
+
+#[doc(hidden)]
+pub const fn concat_attribute_schemas<const N: usize>(parts: &[&[AttributeSchema]]) -> [AttributeSchema; N] {
+    concat(parts, AttributeSchema { name: "", attribute_type: None })
+}
+
+const fn concat<T: Copy, const N: usize>(parts: &[&[T]], filler: T) -> [T; N] {
+    let mut result = [filler; N];
+    let mut index = 0;
+    let mut part = 0;
+    while part < parts.len() {
+        let mut name = 0;
+        while name < parts[part].len() {
+            result[index] = parts[part][name];
+            index += 1;
+            name += 1;
+        }
+        part += 1;
+    }
+    result
+}
+
+// used by the macro to check for collisions between the attributes of a struct and those of its flattened fields
+#[doc(hidden)]
+pub const fn has_duplicate_attribute_names(names: &[&str]) -> bool {
+    let mut first = 0;
+    while first < names.len() {
+        let mut second = first + 1;
+        while second < names.len() {
+            if equal_names(names[first], names[second]) {
+                return true;
+            }
+            second += 1;
+        }
+        first += 1;
+    }
+    false
+}
+
+const fn equal_names(first: &str, second: &str) -> bool {
+    let (first, second) = (first.as_bytes(), second.as_bytes());
+    if first.len() != second.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < first.len() {
+        if first[index] != second[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
@@ -1,17 +0,0 @@
-pub const NEW_METHOD_NAME: &str = "new";
-pub const BUILD_METHOD_NAME: &str = "build";
-
-pub const GET_METHOD_NAME: &str = "get"; // cover get by partition as well
-pub const BATCH_GET_METHOD_NAME: &str = "batch_get";
-pub const CREATE_TABLE_METHOD_NAME: &str = "create_table";
-pub const DELETE_TABLE_METHOD_NAME: &str = "delete_table";
-pub const PUT_METHOD_NAME: &str = "put";
-pub const BATCH_PUT_METHOD_NAME: &str = "batch_put";
-pub const DELETE_METHOD_NAME: &str = "delete";
-pub const SCAN_METHOD_NAME: &str = "scan";
-
-pub const EXCLUSION_ATTRIBUTE_NAME: &str = "exclusion";
-pub const PARTITION_KEY_ATTRIBUTE_NAME: &str = "partition";
-pub const RANGE_KEY_ATTRIBUTE_NAME: &str = "range";
-
-pub const ALL_NUMERIC_TYPES_AS_STRINGS: &[&str] = &["u8", "u16", "u32", "u64", "u128", "i8", "i16", "i32", "i64", "i128", "f32", "f64"];
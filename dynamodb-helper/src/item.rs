@@ -0,0 +1,24 @@
+use crate::ValidationError;
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::collections::HashMap;
+
+/// Implemented by every struct that derives `DynamoDb` and has a partition key, so you can write code that works for all of them,
+/// like the [`Table`](crate::Table) that the generated `Db` structs delegate to.
+pub trait DynamoItem: Sized {
+    /// The generated key struct, e.g. `OrderKey` for `Order`.
+    type Key: Into<HashMap<String, AttributeValue>>;
+    /// The type of the partition key field, used for queries.
+    type PartitionKey;
+    /// The generated parse error, e.g. `OrderDbParseError` for `Order`.
+    type ParseError: std::error::Error;
+
+    const PARTITION_KEY_NAME: &'static str;
+    const RANGE_KEY_NAME: Option<&'static str>;
+
+    /// Fails when a field cannot be converted, like a `#[dynamo(serde)]` field that serde cannot serialize.
+    fn to_item(self) -> Result<HashMap<String, AttributeValue>, ValidationError>;
+
+    fn try_from_item(item: &HashMap<String, AttributeValue>) -> Result<Self, Self::ParseError>;
+
+    fn partition_key_attribute_value(partition: Self::PartitionKey) -> AttributeValue;
+}
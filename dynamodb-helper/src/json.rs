@@ -0,0 +1,22 @@
+//! Saves any value that implements `Serialize` and `Deserialize` as a DynamoDB string containing compact JSON.
+//!
+//! This is the module the macro uses for `#[dynamo(json)]`.
+
+use crate::AttributeType;
+use aws_sdk_dynamodb::types::AttributeValue;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::String);
+
+/// Fails when the value cannot be serialized, for example because it is a map with keys that are not strings.
+pub fn into_attribute_value<T: Serialize>(value: T) -> Result<AttributeValue, String> {
+    serde_json::to_string(&value).map(AttributeValue::S).map_err(|e| e.to_string())
+}
+
+pub fn try_from_attribute_value<T: DeserializeOwned>(value: &AttributeValue) -> Result<T, String> {
+    value
+        .as_s()
+        .map_err(|_| "expected a JSON string".to_string())
+        .and_then(|v| serde_json::from_str(v).map_err(|e| e.to_string()))
+}
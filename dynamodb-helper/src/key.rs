@@ -0,0 +1,44 @@
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use std::hash::{Hash, Hasher};
+
+/// Implemented by types that can serve as a partition or range key, besides the strings, numbers and binaries the macro knows about.
+///
+/// The macro generates an implementation for enums that only have unit variants, which are saved as strings,
+/// and for `#[dynamo(transparent)]` newtypes around a string, number or binary.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be used as a DynamoDB key",
+    note = "DynamoDB only supports strings, numbers and binaries as keys"
+)]
+pub trait KeyAttribute {
+    /// The type of the key attribute, used for creating the table.
+    fn scalar_attribute_type() -> ScalarAttributeType;
+}
+
+impl KeyAttribute for char {
+    fn scalar_attribute_type() -> ScalarAttributeType {
+        ScalarAttributeType::S
+    }
+}
+
+// the generated key structs are hashed and displayed through their attribute values,
+// so the key types do not have to implement `Hash` or `Display` themselves
+
+#[doc(hidden)]
+pub fn hash_key_attribute<H: Hasher>(value: &AttributeValue, state: &mut H) {
+    match value {
+        AttributeValue::S(s) => (0u8, s).hash(state),
+        AttributeValue::N(n) => (1u8, n).hash(state),
+        AttributeValue::B(b) => (2u8, b.as_ref()).hash(state),
+        other => format!("{other:?}").hash(state),
+    }
+}
+
+#[doc(hidden)]
+pub fn key_attribute_to_string(value: &AttributeValue) -> String {
+    match value {
+        AttributeValue::S(s) => s.to_string(),
+        AttributeValue::N(n) => n.to_string(),
+        AttributeValue::B(b) => b.as_ref().iter().map(|byte| format!("{byte:02x}")).collect(),
+        other => format!("{other:?}"),
+    }
+}
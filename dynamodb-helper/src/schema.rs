@@ -0,0 +1,59 @@
+use aws_sdk_dynamodb::types::ScalarAttributeType;
+
+/// The DynamoDB type an attribute is saved as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeType {
+    String,
+    Number,
+    Binary,
+    Boolean,
+    List,
+    Map,
+    StringSet,
+    NumberSet,
+    BinarySet,
+}
+
+/// The name and type of an attribute of a struct that derives `DynamoDb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AttributeSchema {
+    pub name: &'static str,
+    /// `None` when the type depends on a conversion the macro does not know about, like that of a `#[dynamo(with = "...")]` module.
+    pub attribute_type: Option<AttributeType>,
+}
+
+/// The name and type of a partition or range key.
+#[derive(Debug, Clone, Copy)]
+pub struct KeySchema {
+    pub name: &'static str,
+    scalar_attribute_type: fn() -> ScalarAttributeType,
+}
+
+impl KeySchema {
+    pub const fn new(name: &'static str, scalar_attribute_type: fn() -> ScalarAttributeType) -> Self {
+        KeySchema {
+            name,
+            scalar_attribute_type,
+        }
+    }
+
+    /// The type of the key attribute, the same one the generated `create_table` uses.
+    pub fn scalar_attribute_type(&self) -> ScalarAttributeType {
+        (self.scalar_attribute_type)()
+    }
+}
+
+/// Describes the table of a struct that derives `DynamoDb`, available as the `SCHEMA` constant of the struct.
+#[derive(Debug, Clone, Copy)]
+pub struct TableSchema {
+    pub partition_key: KeySchema,
+    pub range_key: Option<KeySchema>,
+    /// Every attribute the struct is saved with, including the keys and the attributes of flattened fields.
+    pub attributes: &'static [AttributeSchema],
+}
+
+impl TableSchema {
+    pub fn attribute(&self, name: &str) -> Option<&AttributeSchema> {
+        self.attributes.iter().find(|attribute| attribute.name == name)
+    }
+}
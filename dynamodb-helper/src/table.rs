@@ -0,0 +1,167 @@
+use crate::{validate_item, DynamoItem, ValidationError};
+use aws_sdk_dynamodb::error::SdkError;
+use aws_sdk_dynamodb::operation::delete_item::{DeleteItemError, DeleteItemOutput};
+use aws_sdk_dynamodb::operation::get_item::GetItemError;
+use aws_sdk_dynamodb::operation::put_item::{PutItemError, PutItemOutput};
+use aws_sdk_dynamodb::operation::query::QueryError;
+use aws_sdk_dynamodb::operation::scan::ScanError;
+use aws_sdk_dynamodb::types::AttributeValue;
+use aws_sdk_dynamodb::Client;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+/// The basic operations on the table of any [`DynamoItem`]. The generated `Db` structs delegate to this, their `to_table` method returns one.
+pub struct Table<T> {
+    pub client: Client,
+    pub table: String,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Table<T> {
+    fn clone(&self) -> Self {
+        Table::new(self.client.clone(), &self.table)
+    }
+}
+
+impl<T> Table<T> {
+    pub fn new(client: Client, table: &str) -> Self {
+        Table {
+            client,
+            table: table.to_string(),
+            item: PhantomData,
+        }
+    }
+}
+
+impl<T: DynamoItem> Table<T> {
+    pub async fn get(&self, key: impl Into<T::Key>) -> Result<Option<T>, TableReadError<GetItemError, T::ParseError>> {
+        let key: T::Key = key.into();
+        let result = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .set_key(Some(key.into()))
+            .send()
+            .await?;
+        let mapped = result
+            .item
+            .as_ref()
+            .map(T::try_from_item)
+            .transpose()
+            .map_err(TableReadError::ParseError)?;
+        Ok(mapped)
+    }
+
+    /// All items with the given partition key, sorted by their range key.
+    pub async fn query(&self, partition: T::PartitionKey) -> Result<Vec<T>, TableReadError<QueryError, T::ParseError>> {
+        let items: Result<Vec<HashMap<String, AttributeValue>>, _> = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("#pk = :pkval")
+            .expression_attribute_names("#pk", T::PARTITION_KEY_NAME)
+            .expression_attribute_values(":pkval", T::partition_key_attribute_value(partition))
+            .into_paginator()
+            .items()
+            .send()
+            .collect()
+            .await;
+
+        parse_items(&items?).map_err(TableReadError::ParseError)
+    }
+
+    pub async fn scan(&self) -> Result<Vec<T>, TableReadError<ScanError, T::ParseError>> {
+        let items: Result<Vec<HashMap<String, AttributeValue>>, _> = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .into_paginator()
+            .items()
+            .send()
+            .collect()
+            .await;
+
+        parse_items(&items?).map_err(TableReadError::ParseError)
+    }
+
+    /// Validates the item before sending it, see [`validate_item`].
+    pub async fn put(&self, item: T) -> Result<PutItemOutput, TableWriteError<PutItemError>> {
+        let item = item.to_item()?;
+        validate_item(&item)?;
+
+        let result = self.client.put_item().table_name(&self.table).set_item(Some(item)).send().await?;
+        Ok(result)
+    }
+
+    pub async fn delete(&self, key: impl Into<T::Key>) -> Result<DeleteItemOutput, SdkError<DeleteItemError>> {
+        let key: T::Key = key.into();
+        self.client
+            .delete_item()
+            .table_name(&self.table)
+            .set_key(Some(key.into()))
+            .send()
+            .await
+    }
+}
+
+fn parse_items<T: DynamoItem>(items: &[HashMap<String, AttributeValue>]) -> Result<Vec<T>, T::ParseError> {
+    items.iter().map(T::try_from_item).collect()
+}
+
+/// Returned by the methods of a [`Table`] that read items, the generated errors of the `Db` structs can be created from it.
+// like those generated errors, it keeps the aws error as is
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum TableReadError<E, P> {
+    ParseError(P),
+    AwsError(SdkError<E>),
+}
+
+impl<E, P> From<SdkError<E>> for TableReadError<E, P> {
+    fn from(err: SdkError<E>) -> Self {
+        TableReadError::AwsError(err)
+    }
+}
+
+impl<E: std::error::Error + 'static, P: Display> Display for TableReadError<E, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableReadError::ParseError(val) => write!(f, "{}", val),
+            TableReadError::AwsError(val) => write!(f, "aws error {}", val),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static, P: Debug + Display> std::error::Error for TableReadError<E, P> {}
+
+/// Returned by the methods of a [`Table`] that write items, which validate the items before sending them.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum TableWriteError<E> {
+    ValidationError(ValidationError),
+    AwsError(SdkError<E>),
+}
+
+impl<E> From<SdkError<E>> for TableWriteError<E> {
+    fn from(err: SdkError<E>) -> Self {
+        TableWriteError::AwsError(err)
+    }
+}
+
+impl<E> From<ValidationError> for TableWriteError<E> {
+    fn from(err: ValidationError) -> Self {
+        TableWriteError::ValidationError(err)
+    }
+}
+
+impl<E: std::error::Error + 'static> Display for TableWriteError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableWriteError::ValidationError(val) => write!(f, "validation error: {}", val),
+            TableWriteError::AwsError(val) => write!(f, "aws error {}", val),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for TableWriteError<E> {}
@@ -0,0 +1,321 @@
+//! Date and time types, saved as an ISO-8601 string by default or as epoch seconds or milliseconds with
+//! `#[dynamo(timestamp = "epoch_seconds")]` or `#[dynamo(timestamp = "epoch_millis")]`.
+//!
+//! The modules in here are the ones the macro uses for each encoding, you can also pass them to `#[dynamo(with = "...")]`.
+
+use crate::{AttributeType, IntoAttributeValue, KeyAttribute, TryFromAttributeValue};
+use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+const SECONDS_PER_DAY: i128 = 86_400;
+
+/// Implemented by the date and time types this crate can save.
+pub trait Timestamp: Sized {
+    /// Nanoseconds since the Unix epoch, negative for earlier moments.
+    fn to_epoch_nanos(&self) -> i128;
+
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String>;
+
+    /// ISO-8601 in UTC with nanosecond precision. Because every string has the same length, they sort in time order.
+    /// That only holds for the years 0 to 9999, other years use the expanded format, with a sign and at least six digits.
+    fn to_iso8601(&self) -> String {
+        format_iso8601(self.to_epoch_nanos())
+    }
+
+    fn from_iso8601(value: &str) -> Result<Self, String> {
+        parse_iso8601(value).and_then(Self::from_epoch_nanos)
+    }
+}
+
+/// Saves a timestamp as an ISO-8601 string, the default encoding.
+pub mod iso8601 {
+    use super::Timestamp;
+    use crate::AttributeType;
+    use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+
+    pub const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::String);
+
+    pub fn into_attribute_value<T: Timestamp>(value: T) -> AttributeValue {
+        AttributeValue::S(value.to_iso8601())
+    }
+
+    pub fn try_from_attribute_value<T: Timestamp>(value: &AttributeValue) -> Result<T, String> {
+        value.as_s().map_err(|_| "expected an ISO-8601 string".to_string()).and_then(|v| T::from_iso8601(v))
+    }
+
+    pub fn scalar_attribute_type() -> ScalarAttributeType {
+        ScalarAttributeType::S
+    }
+}
+
+/// Saves a timestamp as a number of seconds since the Unix epoch, dropping anything smaller than a second.
+pub mod epoch_seconds {
+    use super::{from_epoch_number, to_epoch_number, Timestamp, NANOS_PER_SECOND};
+    use crate::AttributeType;
+    use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+
+    pub const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::Number);
+
+    pub fn into_attribute_value<T: Timestamp>(value: T) -> AttributeValue {
+        to_epoch_number(value, NANOS_PER_SECOND)
+    }
+
+    pub fn try_from_attribute_value<T: Timestamp>(value: &AttributeValue) -> Result<T, String> {
+        from_epoch_number(value, NANOS_PER_SECOND)
+    }
+
+    pub fn scalar_attribute_type() -> ScalarAttributeType {
+        ScalarAttributeType::N
+    }
+}
+
+/// Saves a timestamp as a number of milliseconds since the Unix epoch, dropping anything smaller than a millisecond.
+pub mod epoch_millis {
+    use super::{from_epoch_number, to_epoch_number, Timestamp, NANOS_PER_MILLI};
+    use crate::AttributeType;
+    use aws_sdk_dynamodb::types::{AttributeValue, ScalarAttributeType};
+
+    pub const ATTRIBUTE_TYPE: Option<AttributeType> = Some(AttributeType::Number);
+
+    pub fn into_attribute_value<T: Timestamp>(value: T) -> AttributeValue {
+        to_epoch_number(value, NANOS_PER_MILLI)
+    }
+
+    pub fn try_from_attribute_value<T: Timestamp>(value: &AttributeValue) -> Result<T, String> {
+        from_epoch_number(value, NANOS_PER_MILLI)
+    }
+
+    pub fn scalar_attribute_type() -> ScalarAttributeType {
+        ScalarAttributeType::N
+    }
+}
+
+fn to_epoch_number<T: Timestamp>(value: T, nanos_per_unit: i128) -> AttributeValue {
+    AttributeValue::N(value.to_epoch_nanos().div_euclid(nanos_per_unit).to_string())
+}
+
+fn from_epoch_number<T: Timestamp>(value: &AttributeValue, nanos_per_unit: i128) -> Result<T, String> {
+    let number = value.as_n().map_err(|_| "expected a number".to_string())?;
+    let units: i128 = number.parse().map_err(|_| format!("invalid epoch timestamp '{number}'"))?;
+    units
+        .checked_mul(nanos_per_unit)
+        .ok_or_else(|| format!("epoch timestamp '{number}' is out of range"))
+        .and_then(T::from_epoch_nanos)
+}
+
+// days since 1970-01-01 to a (year, month, day), see http://howardhinnant.github.io/date_algorithms.html
+fn civil_from_days(days: i128) -> (i128, i128, i128) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn days_from_civil(year: i128, month: i128, day: i128) -> i128 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+fn format_iso8601(nanos: i128) -> String {
+    let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+    let subsecond_nanos = nanos.rem_euclid(NANOS_PER_SECOND);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    format!(
+        "{}-{:02}-{:02}T{:02}:{:02}:{:02}.{:09}Z",
+        format_year(year),
+        month,
+        day,
+        second_of_day / 3600,
+        second_of_day % 3600 / 60,
+        second_of_day % 60,
+        subsecond_nanos
+    )
+}
+
+// years outside 0..=9999 do not fit in four digits, so they use the expanded format of ISO-8601, e.g. `+010000` or `-000001`
+fn format_year(year: i128) -> String {
+    if (0..=9999).contains(&year) {
+        format!("{:04}", year)
+    } else {
+        format!("{}{:06}", if year < 0 { '-' } else { '+' }, year.abs())
+    }
+}
+
+// accepts `YYYY-MM-DDTHH:MM:SS`, optionally followed by a fraction, and then `Z` or an offset like `+02:00`
+// the year can also be expanded, a sign followed by at least six digits
+fn parse_iso8601(value: &str) -> Result<i128, String> {
+    let invalid = || format!("invalid ISO-8601 timestamp '{value}'");
+    let (sign, year_start) = match value.as_bytes().first() {
+        Some(b'+') => (1, 1),
+        Some(b'-') => (-1, 1),
+        _ => (1, 0),
+    };
+    let year_digits = value.bytes().skip(year_start).take_while(|b| b.is_ascii_digit()).count();
+    if (year_start == 0 && year_digits != 4) || (year_start == 1 && year_digits < 6) {
+        return Err(invalid());
+    }
+    // where the month would start in a timestamp with a four digit year
+    let shift = year_start + year_digits - 4;
+
+    let number = |start: usize, end: usize| -> Result<i128, String> {
+        value
+            .get(start + shift..end + shift)
+            .filter(|v| v.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|v| v.parse().ok())
+            .ok_or_else(invalid)
+    };
+    let separator = |index: usize, expected: &[u8]| -> Result<(), String> {
+        match value.as_bytes().get(index + shift) {
+            Some(b) if expected.contains(b) => Ok(()),
+            _ => Err(invalid()),
+        }
+    };
+
+    separator(4, b"-")?;
+    separator(7, b"-")?;
+    separator(10, b"Tt ")?;
+    separator(13, b":")?;
+    separator(16, b":")?;
+    let year = value
+        .get(year_start..year_start + year_digits)
+        .and_then(|v| v.parse::<i128>().ok())
+        .map(|v| sign * v)
+        .ok_or_else(invalid)?;
+    let (month, day) = (number(5, 7)?, number(8, 10)?);
+    let (hour, minute, second) = (number(11, 13)?, number(14, 16)?, number(17, 19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return Err(invalid());
+    }
+
+    let rest = value.get(19 + shift..).ok_or_else(invalid)?;
+    let (fraction, offset) = match rest.strip_prefix('.') {
+        Some(with_fraction) => {
+            let digits = with_fraction.bytes().take_while(|b| b.is_ascii_digit()).count();
+            if digits == 0 {
+                return Err(invalid());
+            }
+            (&with_fraction[..digits], &with_fraction[digits..])
+        }
+        None => ("", rest),
+    };
+    // anything beyond nanoseconds is dropped
+    let fraction_nanos = format!("{:0<9}", &fraction[..fraction.len().min(9)]).parse::<i128>().map_err(|_| invalid())?;
+
+    let offset_seconds = match offset {
+        "Z" | "z" => 0,
+        _ if offset.len() == 6 && offset.as_bytes()[3] == b':' => {
+            let sign = match offset.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return Err(invalid()),
+            };
+            let offset_number = |start: usize, end: usize| {
+                offset
+                    .get(start..end)
+                    .filter(|v| v.bytes().all(|b| b.is_ascii_digit()))
+                    .and_then(|v| v.parse::<i128>().ok())
+                    .ok_or_else(invalid)
+            };
+            sign * (offset_number(1, 3)? * 3600 + offset_number(4, 6)? * 60)
+        }
+        _ => return Err(invalid()),
+    };
+
+    let seconds = days_from_civil(year, month, day) * SECONDS_PER_DAY + hour * 3600 + minute * 60 + second - offset_seconds;
+    Ok(seconds * NANOS_PER_SECOND + fraction_nanos)
+}
+
+impl Timestamp for SystemTime {
+    fn to_epoch_nanos(&self) -> i128 {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => after.as_nanos() as i128,
+            Err(e) => -(e.duration().as_nanos() as i128),
+        }
+    }
+
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String> {
+        let out_of_range = || format!("{nanos} nanoseconds since the epoch is out of range");
+        let duration = u64::try_from(nanos.unsigned_abs() / NANOS_PER_SECOND as u128)
+            .map(|seconds| Duration::new(seconds, (nanos.unsigned_abs() % NANOS_PER_SECOND as u128) as u32))
+            .map_err(|_| out_of_range())?;
+
+        if nanos >= 0 {
+            UNIX_EPOCH.checked_add(duration).ok_or_else(out_of_range)
+        } else {
+            UNIX_EPOCH.checked_sub(duration).ok_or_else(out_of_range)
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Timestamp for chrono::DateTime<chrono::Utc> {
+    fn to_epoch_nanos(&self) -> i128 {
+        self.timestamp() as i128 * NANOS_PER_SECOND + self.timestamp_subsec_nanos() as i128
+    }
+
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String> {
+        i64::try_from(nanos.div_euclid(NANOS_PER_SECOND))
+            .ok()
+            .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32))
+            .ok_or_else(|| format!("{nanos} nanoseconds since the epoch is out of range"))
+    }
+}
+
+#[cfg(feature = "time")]
+impl Timestamp for time::OffsetDateTime {
+    fn to_epoch_nanos(&self) -> i128 {
+        self.unix_timestamp_nanos()
+    }
+
+    // always returns a UTC date time
+    fn from_epoch_nanos(nanos: i128) -> Result<Self, String> {
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanos).map_err(|e| e.to_string())
+    }
+}
+
+macro_rules! timestamp_attribute {
+    ($ty:ty) => {
+        impl IntoAttributeValue for $ty {
+            const ATTRIBUTE_TYPE: Option<AttributeType> = iso8601::ATTRIBUTE_TYPE;
+
+            fn into_attribute_value(self) -> AttributeValue {
+                iso8601::into_attribute_value(self)
+            }
+        }
+
+        impl TryFromAttributeValue for $ty {
+            type Error = String;
+
+            fn try_from_attribute_value(value: &AttributeValue) -> Result<Self, Self::Error> {
+                iso8601::try_from_attribute_value(value)
+            }
+        }
+
+        impl KeyAttribute for $ty {
+            fn scalar_attribute_type() -> ScalarAttributeType {
+                iso8601::scalar_attribute_type()
+            }
+        }
+    };
+}
+
+timestamp_attribute!(SystemTime);
+#[cfg(feature = "chrono")]
+timestamp_attribute!(chrono::DateTime<chrono::Utc>);
+#[cfg(feature = "time")]
+timestamp_attribute!(time::OffsetDateTime);
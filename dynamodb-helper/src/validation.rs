@@ -0,0 +1,112 @@
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+// DynamoDB numbers have up to 38 digits of precision, and a magnitude between 1E-130 and 9.99...E+125
+const MAX_NUMBER_PRECISION: usize = 38;
+const MIN_NUMBER_EXPONENT: i64 = -130;
+const MAX_NUMBER_EXPONENT: i64 = 125;
+
+/// Returned by the generated put methods when an item contains a value that DynamoDB would reject, or a value that could not be converted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// Path to the invalid value, like `scores.math` or `items[1].amount`.
+    pub attribute: String,
+    pub message: String,
+}
+
+impl ValidationError {
+    /// An error for a value that could not be converted, the generated code fills in the attribute that contains it.
+    pub fn new(message: impl Display) -> Self {
+        ValidationError {
+            attribute: String::new(),
+            message: message.to_string(),
+        }
+    }
+
+    // used by the generated code to put the name of the attribute that contains the value in front of the path
+    #[doc(hidden)]
+    pub fn within(self, attribute: &str) -> Self {
+        let attribute = if self.attribute.is_empty() {
+            attribute.to_string()
+        } else {
+            format!("{attribute}.{}", self.attribute)
+        };
+        ValidationError { attribute, ..self }
+    }
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid value for {}: {}", self.attribute, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks that every number in the item is one that DynamoDB accepts, so that we do not send a request that is bound to fail.
+pub fn validate_item(item: &HashMap<String, AttributeValue>) -> Result<(), ValidationError> {
+    item.iter().try_for_each(|(name, value)| validate_attribute_value(name, value))
+}
+
+fn validate_attribute_value(path: &str, value: &AttributeValue) -> Result<(), ValidationError> {
+    let invalid = |message: String| ValidationError {
+        attribute: path.to_string(),
+        message,
+    };
+
+    match value {
+        AttributeValue::N(n) => validate_number(n).map_err(invalid),
+        AttributeValue::Ns(numbers) => numbers.iter().try_for_each(|n| validate_number(n).map_err(invalid)),
+        AttributeValue::L(values) => values
+            .iter()
+            .enumerate()
+            .try_for_each(|(i, v)| validate_attribute_value(&format!("{path}[{i}]"), v)),
+        AttributeValue::M(values) => values
+            .iter()
+            .try_for_each(|(k, v)| validate_attribute_value(&format!("{path}.{k}"), v)),
+        _ => Ok(()),
+    }
+}
+
+/// Checks whether the string is a number that DynamoDB accepts, e.g. not `NaN`, not `inf` and not more than 38 digits.
+pub fn validate_number(value: &str) -> Result<(), String> {
+    let invalid = || format!("'{value}' is not a number");
+
+    let unsigned = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().map_err(|_| invalid())?),
+        None => (unsigned, 0),
+    };
+    let (integer_part, fraction_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+
+    if integer_part.is_empty() && fraction_part.is_empty()
+        || !integer_part.bytes().chain(fraction_part.bytes()).all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    // DynamoDB trims leading and trailing zeros, so those do not count towards the precision
+    let digits = format!("{integer_part}{fraction_part}");
+    let significant = digits.trim_start_matches('0');
+    let leading_zeros = digits.len() - significant.len();
+    let significant = significant.trim_end_matches('0');
+
+    if significant.is_empty() {
+        return Ok(());
+    }
+    if significant.len() > MAX_NUMBER_PRECISION {
+        return Err(format!(
+            "'{value}' has {} significant digits, DynamoDB supports at most {MAX_NUMBER_PRECISION}",
+            significant.len()
+        ));
+    }
+
+    // the power of ten of the first significant digit
+    let magnitude = integer_part.len() as i64 - leading_zeros as i64 - 1 + exponent;
+    if !(MIN_NUMBER_EXPONENT..=MAX_NUMBER_EXPONENT).contains(&magnitude) {
+        return Err(format!("'{value}' is outside of the range of numbers that DynamoDB supports"));
+    }
+
+    Ok(())
+}
@@ -47,7 +47,8 @@ mod tests {
     use std::collections::HashMap;
 
     #[derive(DynamoDb)]
-    #[exclusion("new", "get", "batch_get", "put", "batch_put", "delete", "scan", "create_table", "delete_table")]
+    #[exclusion("new", "get", "batch_get", "put", "batch_put", "delete", "scan", "create_table", "delete_table", "to_table")]
+    #[allow(dead_code)]
     pub struct PrettyUselessTestStruct {
         #[partition]
         partition_key: String,
@@ -55,7 +56,7 @@ mod tests {
     }
 
     #[derive(DynamoDb)]
-    #[exclusion("delete_table", "create_table")]
+    #[exclusion("delete_table", "create_table", "to_table")]
     pub struct ExampleTestStruct {
         #[partition]
         partition_key: String,
@@ -0,0 +1,11 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+pub struct Example {
+    #[partition]
+    first: String,
+    #[range]
+    invalid: bool,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+pub struct Order {
+    #[partition]
+    id: String,
+    #[dynamo(rename = "id")]
+    other: String,
+}
+
+#[derive(DynamoDb)]
+#[dynamo(rename_all = "camelCase")]
+pub struct Customer {
+    #[partition]
+    customer_id: String,
+    customerId: String,
+}
+
+fn main() {}
@@ -2,7 +2,7 @@ use dynamodb_helper::DynamoDb;
 
 #[derive(DynamoDb)]
 pub enum SomeEnum {
-    Nested(String)
+    Nested(String, u32)
 }
 
 fn main() {}
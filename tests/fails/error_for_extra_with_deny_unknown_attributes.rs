@@ -0,0 +1,14 @@
+use aws_sdk_dynamodb::types::AttributeValue;
+use dynamodb_helper::DynamoDb;
+use std::collections::HashMap;
+
+#[derive(DynamoDb)]
+#[dynamo(deny_unknown_attributes)]
+pub struct Order {
+    #[partition]
+    id: String,
+    #[dynamo(extra)]
+    rest: HashMap<String, AttributeValue>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+pub struct Report {
+    #[partition]
+    id: String,
+    schema: String,
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+#[dynamo(nested)]
+pub struct AuditFields {
+    created_by: String,
+    created_at: u64,
+}
+
+#[derive(DynamoDb)]
+pub struct Order {
+    #[partition]
+    id: String,
+    created_at: u64,
+    #[dynamo(flatten)]
+    audit: AuditFields,
+}
+
+fn main() {}
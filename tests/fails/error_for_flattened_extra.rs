@@ -0,0 +1,22 @@
+use aws_sdk_dynamodb::types::AttributeValue;
+use dynamodb_helper::DynamoDb;
+use std::collections::HashMap;
+
+#[derive(DynamoDb)]
+#[dynamo(nested)]
+pub struct Metadata {
+    source: String,
+    #[dynamo(extra)]
+    rest: HashMap<String, AttributeValue>,
+}
+
+#[derive(DynamoDb)]
+pub struct Order {
+    #[partition]
+    id: String,
+    name: String,
+    #[dynamo(flatten)]
+    metadata: Metadata,
+}
+
+fn main() {}
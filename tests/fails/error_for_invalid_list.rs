@@ -5,7 +5,7 @@ pub struct Example {
     #[partition]
     first: String,
     second: u32,
-    invalid: Vec<bool>,
+    invalid: Vec<(String, u32)>,
 }
 
 fn main() {}
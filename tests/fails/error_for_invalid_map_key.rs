@@ -0,0 +1,12 @@
+use dynamodb_helper::DynamoDb;
+use std::collections::HashMap;
+
+#[derive(DynamoDb)]
+pub struct Example {
+    #[partition]
+    first: String,
+    second: u32,
+    invalid: HashMap<u32, String>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+pub struct Example {
+    #[partition]
+    invalid: Option<String>,
+    second: u32,
+}
+
+fn main() {}
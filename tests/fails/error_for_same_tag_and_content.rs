@@ -0,0 +1,10 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+#[dynamo(tag = "kind", content = "kind")]
+pub enum Payment {
+    Cash,
+    Voucher(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dynamodb_helper::DynamoDb;
+use std::collections::HashSet;
+
+#[derive(DynamoDb)]
+pub struct Example {
+    #[partition]
+    first: String,
+    invalid: Vec<HashSet<String>>,
+}
+
+fn main() {}
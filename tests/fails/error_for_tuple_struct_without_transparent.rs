@@ -0,0 +1,6 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+pub struct OrderId(String);
+
+fn main() {}
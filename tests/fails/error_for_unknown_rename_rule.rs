@@ -0,0 +1,10 @@
+use dynamodb_helper::DynamoDb;
+
+#[derive(DynamoDb)]
+#[dynamo(rename_all = "kebab-case")]
+pub struct Order {
+    #[partition]
+    order_id: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dynamodb_helper::DynamoDb;
+use std::time::SystemTime;
+
+#[derive(DynamoDb)]
+pub struct Event {
+    #[partition]
+    id: String,
+    #[dynamo(timestamp = "epoch_minutes")]
+    at: SystemTime,
+}
+
+fn main() {}
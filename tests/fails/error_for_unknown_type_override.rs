@@ -0,0 +1,13 @@
+use dynamodb_helper::DynamoDb;
+
+type Amount = u64;
+
+#[derive(DynamoDb)]
+pub struct Order {
+    #[partition]
+    order_id: String,
+    #[dynamo(type = "integer")]
+    amount: Amount,
+}
+
+fn main() {}
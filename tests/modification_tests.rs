@@ -1,6 +1,38 @@
 pub mod util;
+use aws_sdk_dynamodb::types::AttributeValue;
+use dynamodb_helper::{DynamoDb, DynamoItem, Table};
+use std::cell::Cell;
+use std::collections::HashMap;
 use util::*;
 
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithTransientFields {
+    #[partition]
+    an_id: String,
+    prices: Vec<u32>,
+    #[dynamo(skip_serializing_if = "Vec::is_empty")]
+    comments: Vec<String>,
+    #[dynamo(skip_serializing_if = "Option::is_none", store_null)]
+    note: Option<String>,
+    #[dynamo(skip)]
+    cached_total: Option<u32>,
+    #[dynamo(skip, default = "not_checked")]
+    checked: Cell<bool>,
+}
+
+fn not_checked() -> Cell<bool> {
+    Cell::new(false)
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithExtraAttributes {
+    #[partition]
+    an_id: String,
+    name: String,
+    #[dynamo(extra)]
+    rest: HashMap<String, AttributeValue>,
+}
+
 #[tokio::test]
 async fn should_be_able_to_put() {
     let put_table = "putTable";
@@ -164,7 +196,7 @@ async fn should_be_able_to_delete_with_range() {
 
     let db = OrderStructWithRangeDb::new(client_for_struct, delete_table);
 
-    db.delete(example.an_id.to_string(), example.a_range).await.expect("Delete to work");
+    db.delete((example.an_id.to_string(), example.a_range)).await.expect("Delete to work");
 
     let result = get_order_struct_with_range(delete_table, &client, example.an_id.as_str(), &example.a_range).await;
 
@@ -172,3 +204,119 @@ async fn should_be_able_to_delete_with_range() {
 
     assert!(result.item().is_none());
 }
+
+#[tokio::test]
+async fn should_not_put_skipped_fields() {
+    let put_table = "putSkippedTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithTransientFields {
+        an_id: "uid123".to_string(),
+        prices: vec![2, 3],
+        comments: vec![],
+        note: None,
+        cached_total: Some(5),
+        checked: Cell::new(true),
+    };
+
+    init_table(&client, put_table, "an_id", None).await;
+
+    let db = OrderWithTransientFieldsDb::new(client_for_struct, put_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw_result = get_order_struct(put_table, &client, "uid123").await;
+    let result = db.get("uid123".to_string()).await.expect("Get to work");
+
+    destroy_table(&client, put_table).await;
+
+    let mut attribute_names: Vec<&String> = raw_result.item().expect("Item to exist").keys().collect();
+    attribute_names.sort();
+    assert_eq!(attribute_names, vec!["an_id", "prices"]);
+    assert_eq!(
+        result,
+        Some(OrderWithTransientFields {
+            cached_total: None,
+            checked: Cell::new(false),
+            ..example
+        })
+    );
+}
+
+#[tokio::test]
+async fn should_keep_extra_attributes_when_updating() {
+    let put_table = "putExtraTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let other_attributes = HashMap::from([
+        ("written_by".to_string(), AttributeValue::S("another service".to_string())),
+        ("counter".to_string(), AttributeValue::N("3".to_string())),
+    ]);
+    let mut item = other_attributes.clone();
+    item.insert("an_id".to_string(), AttributeValue::S("uid123".to_string()));
+    item.insert("name".to_string(), AttributeValue::S("first name".to_string()));
+
+    init_table(&client, put_table, "an_id", None).await;
+    put_hashmap(put_table, &client, item).await;
+
+    let db = OrderWithExtraAttributesDb::new(client_for_struct, put_table);
+
+    let mut order = db.get("uid123".to_string()).await.expect("Get to work").expect("Item to exist");
+    order.name = "second name".to_string();
+    db.put(order.clone()).await.expect("Put to work");
+
+    let raw_result = get_order_struct(put_table, &client, "uid123").await;
+
+    destroy_table(&client, put_table).await;
+
+    let mut expected = other_attributes.clone();
+    expected.insert("an_id".to_string(), AttributeValue::S("uid123".to_string()));
+    expected.insert("name".to_string(), AttributeValue::S("second name".to_string()));
+    assert_eq!(order.rest, other_attributes);
+    assert_eq!(raw_result.item().expect("Item to exist"), &expected);
+}
+
+// only relies on the trait, so it works for every struct that derives `DynamoDb`
+async fn put_and_replace<T: DynamoItem>(table: &Table<T>, first: T, second: T, key: T::Key) -> Option<T>
+where
+    T::ParseError: std::fmt::Debug,
+{
+    table.put(first).await.expect("Put to work");
+    table.put(second).await.expect("Put to work");
+    table.get(key).await.expect("Get to work")
+}
+
+#[tokio::test]
+async fn should_be_able_to_use_the_generic_table() {
+    let put_table = "genericTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = create_order_struct_with_range();
+    let other = OrderStructWithRange {
+        a_range: 2000,
+        ..example.clone()
+    };
+    let updated = OrderStructWithRange {
+        name: "Someone else".to_string(),
+        ..example.clone()
+    };
+
+    init_table(&client, put_table, "an_id", Some("a_range")).await;
+
+    let db = OrderStructWithRangeDb::new(client_for_struct, put_table);
+    let table = db.to_table();
+
+    let result = put_and_replace(&table, example.clone(), updated, OrderStructWithRangeKey::from(&example)).await;
+    table.put(other).await.expect("Put to work");
+    let queried = table.query(example.an_id.clone()).await.expect("Query to work");
+    table.delete((example.an_id.clone(), 2000)).await.expect("Delete to work");
+    let scanned = db.scan().await.expect("Scan to work");
+
+    destroy_table(&client, put_table).await;
+
+    assert_eq!(OrderStructWithRange::PARTITION_KEY_NAME, "an_id");
+    assert_eq!(OrderStructWithRange::RANGE_KEY_NAME, Some("a_range"));
+    assert_eq!(result.expect("Item to exist").name, "Someone else");
+    assert_eq!(queried.iter().map(|o| o.a_range).collect::<Vec<_>>(), vec![1000, 2000]);
+    assert_eq!(scanned.iter().map(|o| o.a_range).collect::<Vec<_>>(), vec![1000]);
+}
@@ -1,7 +1,8 @@
 extern crate core;
 
 use aws_sdk_dynamodb::types::AttributeValue;
-use std::collections::HashMap;
+use dynamodb_helper::DynamoDb;
+use std::collections::{HashMap, HashSet};
 use std::iter::Iterator;
 
 pub mod util;
@@ -66,6 +67,123 @@ async fn should_return_error_result_when_parsing_fails_for_get() {
     };
 }
 
+#[derive(DynamoDb, Debug, PartialEq)]
+pub struct OrderWithNewFields {
+    #[partition]
+    an_id: String,
+    name: String,
+    #[dynamo(default)]
+    total_amount: u32,
+    #[dynamo(default = "default_currency")]
+    currency: String,
+    #[dynamo(default = "default_discount")]
+    discount: Option<u8>,
+    #[dynamo(default)]
+    tags: HashSet<String>,
+}
+
+fn default_currency() -> String {
+    "EUR".to_string()
+}
+
+fn default_discount() -> Option<u8> {
+    Some(10)
+}
+
+#[derive(DynamoDb, Debug, PartialEq)]
+#[dynamo(default)]
+pub struct OrderWithAllNewFields {
+    #[partition]
+    an_id: String,
+    name: String,
+    numbers: Vec<i32>,
+    a_boolean: bool,
+}
+
+#[derive(DynamoDb, Debug, PartialEq)]
+#[dynamo(deny_unknown_attributes)]
+pub struct StrictOrder {
+    #[partition]
+    an_id: String,
+    name: String,
+}
+
+#[tokio::test]
+async fn should_return_error_result_for_unknown_attributes_when_denied() {
+    let get_table = "getUnknownAttributesTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+
+    init_table(&client, get_table, "an_id", None).await;
+
+    let db = StrictOrderDb::new(client_for_struct, get_table);
+
+    let known_map = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("name".to_string(), AttributeValue::S("an order".to_string())),
+    ]);
+    let mut unknown_map = known_map.clone();
+    unknown_map.insert("an_id".to_string(), AttributeValue::S("uid456".to_string()));
+    unknown_map.insert("written_by".to_string(), AttributeValue::S("someone".to_string()));
+    unknown_map.insert("counter".to_string(), AttributeValue::N("1".to_string()));
+    put_hashmap(get_table, &client, known_map).await;
+    put_hashmap(get_table, &client, unknown_map).await;
+
+    let known_result = db.get("uid123".to_string()).await;
+    let unknown_result = db.get("uid456".to_string()).await;
+
+    destroy_table(&client, get_table).await;
+
+    assert!(known_result.is_ok());
+    match unknown_result {
+        Err(StrictOrderDbGetError::ParseError(v)) => assert_eq!(v, "Found unknown attributes counter, written_by"),
+        _ => panic!("Did not find expected error result"),
+    };
+}
+
+#[tokio::test]
+async fn should_use_defaults_for_missing_attributes() {
+    let get_table = "getDefaultsTable";
+    let client = create_client().await;
+
+    init_table(&client, get_table, "an_id", None).await;
+
+    let db = OrderWithNewFieldsDb::new(create_client().await, get_table);
+    let all_defaults_db = OrderWithAllNewFieldsDb::new(create_client().await, get_table);
+
+    let basic_map = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("name".to_string(), AttributeValue::S("an order".to_string())),
+    ]);
+    put_hashmap(get_table, &client, basic_map).await;
+
+    let result = db.get("uid123".to_string()).await.expect("To be able to get a result");
+    let all_defaults_result = all_defaults_db.get("uid123".to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, get_table).await;
+
+    assert_eq!(
+        result,
+        Some(OrderWithNewFields {
+            an_id: "uid123".to_string(),
+            name: "an order".to_string(),
+            total_amount: 0,
+            currency: "EUR".to_string(),
+            discount: Some(10),
+            tags: HashSet::new(),
+        })
+    );
+    assert_eq!(
+        all_defaults_result,
+        Some(OrderWithAllNewFields {
+            an_id: "uid123".to_string(),
+            name: "an order".to_string(),
+            numbers: vec![],
+            a_boolean: false,
+        })
+    );
+}
+
 #[tokio::test]
 async fn should_be_able_to_get_from_dynamo_with_range_key() {
     let get_table = "getRangeTable";
@@ -80,7 +198,7 @@ async fn should_be_able_to_get_from_dynamo_with_range_key() {
     put_order_with_range_struct(get_table, &client, &example).await;
 
     let result_option = db
-        .get(example.an_id.to_string(), example.a_range)
+        .get((example.an_id.to_string(), example.a_range))
         .await
         .expect("To be able to get a result");
 
@@ -155,7 +273,7 @@ async fn should_return_error_result_when_parsing_fails_for_get_by_partition() {
         ("total_amount".to_string(), AttributeValue::S("not a number".to_string())),
         (
             "names".to_string(),
-            AttributeValue::L(example.names.iter().map(|v| v.clone()).map(|v| AttributeValue::S(v)).collect()),
+            AttributeValue::L(example.names.iter().cloned().map(AttributeValue::S).collect()),
         ),
         (
             "map_values".to_string(),
@@ -311,3 +429,59 @@ async fn should_return_error_result_when_parsing_fails_for_scan() {
         _ => panic!("Did not find expected error result"),
     };
 }
+
+#[tokio::test]
+async fn should_be_able_to_get_with_keys_taken_from_items() {
+    let get_table = "getWithKeysTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = create_order_struct_with_range();
+    let second_example = OrderStructWithRange {
+        a_range: 1001,
+        ..create_order_struct_with_range()
+    };
+
+    init_table(&client, get_table, "an_id", Some("a_range")).await;
+
+    let db = OrderStructWithRangeDb::new(client_for_struct, get_table);
+
+    put_order_with_range_struct(get_table, &client, &example).await;
+    put_order_with_range_struct(get_table, &client, &second_example).await;
+
+    let single_result = db.get(&example).await.expect("Get to work");
+    let batch_result = db
+        .batch_get(vec![OrderStructWithRangeKey::from(&example), OrderStructWithRangeKey::from(&second_example)])
+        .await
+        .expect("Batch get to succeed");
+
+    destroy_table(&client, get_table).await;
+
+    assert_eq!(single_result.map(|v| v.a_range), Some(example.a_range));
+    assert_eq!(
+        batch_result.iter().map(OrderStructWithRangeKey::from).collect::<HashSet<_>>(),
+        HashSet::from([
+            OrderStructWithRangeKey::from((example.an_id.clone(), example.a_range)),
+            OrderStructWithRangeKey::from((second_example.an_id.clone(), second_example.a_range)),
+        ])
+    );
+}
+
+#[test]
+fn should_convert_keys_to_and_from_attribute_values() {
+    let key = OrderStructWithRangeKey::from(&create_order_struct_with_range());
+
+    let map: HashMap<String, AttributeValue> = key.clone().into();
+    let parsed = OrderStructWithRangeKey::try_from(&map).expect("Parsing the key to work");
+    let missing_range = OrderStructWithRangeKey::try_from(HashMap::from([("an_id".to_string(), AttributeValue::S("uid123".to_string()))]));
+
+    assert_eq!(
+        map,
+        HashMap::from([
+            ("an_id".to_string(), AttributeValue::S(key.an_id.clone())),
+            ("a_range".to_string(), AttributeValue::N(key.a_range.to_string())),
+        ])
+    );
+    assert_eq!(parsed, key);
+    assert_eq!(key.to_string(), format!("an_id={}, a_range={}", key.an_id, key.a_range));
+    assert_eq!(missing_range.unwrap_err().to_string(), "Parse error: Did not find required attribute a_range");
+}
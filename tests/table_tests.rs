@@ -2,9 +2,62 @@ pub mod util;
 
 use aws_sdk_dynamodb::error::SdkError;
 use aws_sdk_dynamodb::operation::create_table::CreateTableError;
-use aws_sdk_dynamodb::types::KeyType;
+use aws_sdk_dynamodb::types::{AttributeValue, KeyType, ScalarAttributeType};
+use dynamodb_helper::{AttributeSchema, AttributeType, DynamoDb};
+use std::collections::{HashMap, HashSet};
+use std::time::SystemTime;
 use util::*;
 
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested, rename_all = "PascalCase")]
+pub struct ShippingInfo {
+    pub street_name: String,
+    #[dynamo(rename = "zip")]
+    pub postal_code: String,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(rename_all = "camelCase")]
+pub struct RenamedOrder {
+    #[partition]
+    pub order_id: String,
+    #[range]
+    pub created_at: u64,
+    #[dynamo(rename = "total")]
+    pub total_amount: u32,
+    pub shipping_info: ShippingInfo,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct ScreamingOrder {
+    #[partition]
+    pub order_id: String,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested)]
+pub struct AuditInfo {
+    pub created_by: String,
+    pub revision: Option<u32>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct CatalogItem {
+    #[partition]
+    pub sku: String,
+    #[range]
+    #[dynamo(timestamp = "epoch_seconds")]
+    pub listed: SystemTime,
+    pub tags: HashSet<String>,
+    pub prices: HashMap<String, f64>,
+    pub shipping: Option<ShippingInfo>,
+    #[dynamo(flatten)]
+    pub audit: AuditInfo,
+    #[dynamo(skip)]
+    pub cached: bool,
+}
+
 #[tokio::test]
 async fn should_be_able_to_create_a_table() {
     let create_table = "createTableTable";
@@ -34,6 +87,33 @@ async fn should_be_able_to_create_a_table() {
     destroy_table(&client, create_table).await;
 }
 
+#[tokio::test]
+async fn should_create_a_table_with_the_right_key_attribute_types() {
+    let create_table = "createTableAttributeTypesTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+
+    let db = OrderStructWithRangeDb::new(client_for_struct, create_table);
+
+    db.create_table().await.expect("Create table to work");
+
+    let result = client
+        .describe_table()
+        .table_name(create_table)
+        .send()
+        .await
+        .expect("To be able to describe tables");
+
+    destroy_table(&client, create_table).await;
+
+    let definitions = result.table.unwrap().attribute_definitions.unwrap();
+    let partition = definitions.iter().find(|d| d.attribute_name == "an_id").unwrap();
+    let range = definitions.iter().find(|d| d.attribute_name == "a_range").unwrap();
+
+    assert_eq!(partition.attribute_type, ScalarAttributeType::S);
+    assert_eq!(range.attribute_type, ScalarAttributeType::N);
+}
+
 #[tokio::test]
 async fn should_return_error_result_when_creating_table_twice() {
     let create_table = "createTableTwiceTable";
@@ -100,7 +180,7 @@ async fn should_be_able_to_delete_a_table() {
 
     let filtered = results.table_names.filter(|t| t.iter().any(|tab| tab == &delete_table.to_string()));
 
-    assert!(filtered.is_none() || filtered.unwrap().len() == 0);
+    assert!(filtered.is_none() || filtered.unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -119,3 +199,98 @@ async fn should_returning_error_result_when_deleting_twice() {
 
     assert!(result.is_err());
 }
+
+#[tokio::test]
+async fn should_use_renamed_attributes_for_table_and_items() {
+    let create_table = "createRenamedTable";
+    let client = create_client().await;
+    let order = RenamedOrder {
+        order_id: "uid123".to_string(),
+        created_at: 1700000000,
+        total_amount: 5,
+        shipping_info: ShippingInfo {
+            street_name: "Main Street".to_string(),
+            postal_code: "1000".to_string(),
+        },
+    };
+
+    let db = RenamedOrderDb::new(create_client().await, create_table);
+    db.create_table().await.expect("Create table to work");
+    db.put(order.clone()).await.expect("Put to work");
+
+    let description = client
+        .describe_table()
+        .table_name(create_table)
+        .send()
+        .await
+        .expect("To be able to describe tables");
+    let raw_result = client
+        .get_item()
+        .table_name(create_table)
+        .key("orderId", AttributeValue::S("uid123".to_string()))
+        .key("createdAt", AttributeValue::N("1700000000".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let get_result = db.get(("uid123".to_string(), 1700000000)).await.expect("Get to work");
+    let batch_get_result = db.batch_get(vec![("uid123".to_string(), 1700000000)]).await.expect("Batch get to work");
+    db.delete(("uid123".to_string(), 1700000000)).await.expect("Delete to work");
+    let get_after_delete_result = db.get(("uid123".to_string(), 1700000000)).await.expect("Get to work");
+
+    destroy_table(&client, create_table).await;
+
+    let key_schema = description.table.unwrap().key_schema.unwrap();
+    let raw_item = raw_result.item.unwrap();
+    assert_eq!(key_schema[0].attribute_name, "orderId");
+    assert_eq!(key_schema[1].attribute_name, "createdAt");
+    assert_eq!(raw_item.get("total"), Some(&AttributeValue::N("5".to_string())));
+    assert_eq!(
+        raw_item.get("shippingInfo"),
+        Some(&AttributeValue::M(
+            [
+                ("StreetName".to_string(), AttributeValue::S("Main Street".to_string())),
+                ("zip".to_string(), AttributeValue::S("1000".to_string())),
+            ]
+            .into()
+        ))
+    );
+    assert_eq!(get_result, Some(order.clone()));
+    assert_eq!(batch_get_result, vec![order]);
+    assert_eq!(get_after_delete_result, None);
+    assert_eq!(
+        std::collections::HashMap::try_from(ScreamingOrder {
+            order_id: "uid123".to_string()
+        })
+        .expect("Conversion to work")
+        .get("ORDER_ID"),
+        Some(&AttributeValue::S("uid123".to_string()))
+    );
+}
+
+#[test]
+fn should_describe_attribute_names_and_schema() {
+    let renamed_schema = RenamedOrder::SCHEMA;
+    let catalog_schema = CatalogItem::SCHEMA;
+
+    assert_eq!(RenamedOrder::ORDER_ID, "orderId");
+    assert_eq!(RenamedOrder::TOTAL_AMOUNT, "total");
+    assert_eq!(ShippingInfo::POSTAL_CODE, "zip");
+    assert_eq!(renamed_schema.partition_key.name, RenamedOrder::ORDER_ID);
+    assert_eq!(renamed_schema.partition_key.scalar_attribute_type(), ScalarAttributeType::S);
+    assert_eq!(renamed_schema.range_key.map(|k| (k.name, k.scalar_attribute_type())), Some(("createdAt", ScalarAttributeType::N)));
+    assert!(ScreamingOrder::SCHEMA.range_key.is_none());
+    assert_eq!(catalog_schema.range_key.map(|k| k.scalar_attribute_type()), Some(ScalarAttributeType::N));
+    assert_eq!(
+        catalog_schema.attributes,
+        &[
+            AttributeSchema { name: "sku", attribute_type: Some(AttributeType::String) },
+            AttributeSchema { name: "listed", attribute_type: Some(AttributeType::Number) },
+            AttributeSchema { name: "tags", attribute_type: Some(AttributeType::StringSet) },
+            AttributeSchema { name: "prices", attribute_type: Some(AttributeType::Map) },
+            AttributeSchema { name: "shipping", attribute_type: Some(AttributeType::Map) },
+            AttributeSchema { name: "created_by", attribute_type: Some(AttributeType::String) },
+            AttributeSchema { name: "revision", attribute_type: Some(AttributeType::Number) },
+        ]
+    );
+    assert_eq!(catalog_schema.attribute(AuditInfo::REVISION).and_then(|a| a.attribute_type), Some(AttributeType::Number));
+}
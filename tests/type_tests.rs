@@ -0,0 +1,1483 @@
+use aws_sdk_dynamodb::primitives::Blob;
+use bytes::Bytes;
+use aws_sdk_dynamodb::types::AttributeValue;
+use bigdecimal::BigDecimal;
+use dynamodb_helper::{DynamoDb, IntoAttributeValue, TryFromAttributeValue, ValidationError};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use indexmap::IndexMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub mod util;
+use util::*;
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested)]
+pub struct Address {
+    pub street: String,
+    pub number: u32,
+    pub box_number: Option<String>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested)]
+pub struct LineItem {
+    pub product: String,
+    pub amount: i32,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithNested {
+    #[partition]
+    pub an_id: String,
+    pub address: Address,
+    pub billing_address: Option<Address>,
+    pub items: Vec<LineItem>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_nested_structs() {
+    let nested_table = "nestedTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithNested {
+        an_id: "uid123".to_string(),
+        address: Address {
+            street: "Main street".to_string(),
+            number: 5,
+            box_number: Some("b".to_string()),
+        },
+        billing_address: None,
+        items: vec![
+            LineItem {
+                product: "book".to_string(),
+                amount: 2,
+            },
+            LineItem {
+                product: "pen".to_string(),
+                amount: 1,
+            },
+        ],
+    };
+
+    init_table(&client, nested_table, "an_id", None).await;
+
+    let db = OrderWithNestedDb::new(client_for_struct, nested_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(nested_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, nested_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert!(matches!(raw_item.get("address"), Some(AttributeValue::M(_))));
+    assert!(raw_item.get("billing_address").is_none());
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithCollections {
+    #[partition]
+    pub an_id: String,
+    pub flags: Vec<bool>,
+    pub matrix: Vec<Vec<String>>,
+    pub numbers_per_name: HashMap<String, Vec<i32>>,
+    pub labels: Vec<HashMap<String, String>>,
+    pub scores: HashMap<String, f64>,
+    pub enabled: HashMap<String, bool>,
+    pub optional_values: Vec<Option<u8>>,
+    pub optional_nested: Option<HashMap<String, Vec<Option<bool>>>>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_nested_collections() {
+    let collections_table = "collectionsTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithCollections {
+        an_id: "uid123".to_string(),
+        flags: vec![true, false],
+        matrix: vec![vec!["a".to_string(), "b".to_string()], vec![]],
+        numbers_per_name: HashMap::from([("first".to_string(), vec![1, 2]), ("second".to_string(), vec![])]),
+        labels: vec![HashMap::from([("color".to_string(), "red".to_string())])],
+        scores: HashMap::from([("math".to_string(), 7.5)]),
+        enabled: HashMap::from([("feature".to_string(), true)]),
+        optional_values: vec![Some(1), None],
+        optional_nested: Some(HashMap::from([("deep".to_string(), vec![Some(true), None])])),
+    };
+
+    init_table(&client, collections_table, "an_id", None).await;
+
+    let db = OrderWithCollectionsDb::new(client_for_struct, collections_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(collections_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, collections_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(
+        raw_item.get("optional_values"),
+        Some(&AttributeValue::L(vec![AttributeValue::N("1".to_string()), AttributeValue::Null(true)]))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithSets {
+    #[partition]
+    pub an_id: String,
+    pub tags: HashSet<String>,
+    pub product_ids: BTreeSet<u64>,
+    pub empty_tags: HashSet<String>,
+    pub optional_numbers: Option<HashSet<i32>>,
+    #[dynamo(list)]
+    pub tags_as_list: BTreeSet<String>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_sets() {
+    let sets_table = "setsTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithSets {
+        an_id: "uid123".to_string(),
+        tags: HashSet::from(["new".to_string(), "urgent".to_string()]),
+        product_ids: BTreeSet::from([3, 1, 2]),
+        empty_tags: HashSet::new(),
+        optional_numbers: Some(HashSet::from([5])),
+        tags_as_list: BTreeSet::from(["a".to_string(), "b".to_string()]),
+    };
+
+    init_table(&client, sets_table, "an_id", None).await;
+
+    let db = OrderWithSetsDb::new(client_for_struct, sets_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(sets_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, sets_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert!(matches!(raw_item.get("tags"), Some(AttributeValue::Ss(_))));
+    assert!(matches!(raw_item.get("product_ids"), Some(AttributeValue::Ns(_))));
+    assert!(raw_item.get("empty_tags").is_none());
+    assert!(matches!(raw_item.get("tags_as_list"), Some(AttributeValue::L(_))));
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithBinaries {
+    #[partition]
+    pub an_id: Vec<u8>,
+    pub thumbnail: Vec<u8>,
+    pub hash: [u8; 4],
+    pub payload: Blob,
+    pub raw: Bytes,
+    pub optional_bytes: Option<Vec<u8>>,
+    pub chunks: HashSet<Vec<u8>>,
+    #[dynamo(list)]
+    pub small_numbers: Vec<u8>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_binaries_with_binary_key() {
+    let binary_table = "binaryTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithBinaries {
+        an_id: vec![1, 2, 3],
+        thumbnail: vec![255, 0, 255],
+        hash: [1, 2, 3, 4],
+        payload: Blob::new(vec![9, 8]),
+        raw: Bytes::from_static(b"raw"),
+        optional_bytes: None,
+        chunks: HashSet::from([vec![1], vec![2, 3]]),
+        small_numbers: vec![1, 2],
+    };
+
+    let db = OrderWithBinariesDb::new(client_for_struct, binary_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(binary_table)
+        .key("an_id", AttributeValue::B(Blob::new(example.an_id.clone())))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db.get(example.an_id.clone()).await.expect("To be able to get a result");
+
+    destroy_table(&client, binary_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(raw_item.get("thumbnail"), Some(&AttributeValue::B(Blob::new(vec![255, 0, 255]))));
+    assert!(matches!(raw_item.get("chunks"), Some(AttributeValue::Bs(_))));
+    assert!(matches!(raw_item.get("small_numbers"), Some(AttributeValue::L(_))));
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub enum OrderStatus {
+    Pending,
+    #[dynamo(rename = "SHIPPED")]
+    Shipped,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(tag = "kind")]
+pub enum Payment {
+    Cash,
+    Card { number: String, expiry: Option<String> },
+    Voucher(String),
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithEnums {
+    #[partition]
+    pub an_id: String,
+    #[range]
+    pub status: OrderStatus,
+    pub payment: Payment,
+    pub other_payments: Vec<Payment>,
+    pub previous_status: Option<OrderStatus>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_enums_with_enum_range_key() {
+    let enum_table = "enumTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithEnums {
+        an_id: "uid123".to_string(),
+        status: OrderStatus::Shipped,
+        payment: Payment::Card {
+            number: "1234".to_string(),
+            expiry: None,
+        },
+        other_payments: vec![Payment::Cash, Payment::Voucher("FREE".to_string())],
+        previous_status: Some(OrderStatus::Pending),
+    };
+
+    let db = OrderWithEnumsDb::new(client_for_struct, enum_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(enum_table)
+        .key("an_id", AttributeValue::S(example.an_id.clone()))
+        .key("status", AttributeValue::S("SHIPPED".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db
+        .get((example.an_id.clone(), example.status.clone()))
+        .await
+        .expect("To be able to get a result");
+
+    destroy_table(&client, enum_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(
+        raw_item.get("payment"),
+        Some(&AttributeValue::M(HashMap::from([
+            ("kind".to_string(), AttributeValue::S("Card".to_string())),
+            ("number".to_string(), AttributeValue::S("1234".to_string())),
+        ])))
+    );
+    assert_eq!(
+        raw_item.get("other_payments"),
+        Some(&AttributeValue::L(vec![
+            AttributeValue::M(HashMap::from([("kind".to_string(), AttributeValue::S("Cash".to_string()))])),
+            AttributeValue::M(HashMap::from([
+                ("kind".to_string(), AttributeValue::S("Voucher".to_string())),
+                ("content".to_string(), AttributeValue::S("FREE".to_string())),
+            ])),
+        ]))
+    );
+    assert_eq!(raw_item.get("previous_status"), Some(&AttributeValue::S("Pending".to_string())));
+    assert_eq!(result, Some(example));
+}
+
+#[test]
+fn should_give_parse_error_listing_variants_for_unknown_enum_value() {
+    let unit_result = OrderStatus::try_from(&AttributeValue::S("Lost".to_string()));
+    let tagged_result = Payment::try_from(&AttributeValue::M(HashMap::from([(
+        "kind".to_string(),
+        AttributeValue::S("Crypto".to_string()),
+    )])));
+
+    assert_eq!(
+        unit_result.unwrap_err().to_string(),
+        "Parse error: Unknown variant 'Lost', expected one of: Pending, SHIPPED"
+    );
+    assert_eq!(
+        tagged_result.unwrap_err().to_string(),
+        "Parse error: Unknown variant 'Crypto', expected one of: Cash, Card, Voucher"
+    );
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(transparent)]
+pub struct OrderId(String);
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(transparent)]
+pub struct Cents(i64);
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithNewtypes {
+    #[partition]
+    pub an_id: OrderId,
+    #[range]
+    pub total: Cents,
+    pub discount: Option<Cents>,
+    pub related_orders: Vec<OrderId>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_get_and_delete_with_newtype_keys() {
+    let newtype_table = "newtypeTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithNewtypes {
+        an_id: OrderId("uid123".to_string()),
+        total: Cents(1250),
+        discount: Some(Cents(100)),
+        related_orders: vec![OrderId("uid456".to_string())],
+    };
+
+    let db = OrderWithNewtypesDb::new(client_for_struct, newtype_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(newtype_table)
+        .key("an_id", AttributeValue::S("uid123".to_string()))
+        .key("total", AttributeValue::N("1250".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db
+        .get((example.an_id.clone(), example.total.clone()))
+        .await
+        .expect("To be able to get a result");
+    db.delete((example.an_id.clone(), example.total.clone())).await.expect("Delete to work");
+    let after_delete = db
+        .get((example.an_id.clone(), example.total.clone()))
+        .await
+        .expect("To be able to get a result");
+
+    destroy_table(&client, newtype_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(raw_item.get("discount"), Some(&AttributeValue::N("100".to_string())));
+    assert_eq!(
+        raw_item.get("related_orders"),
+        Some(&AttributeValue::L(vec![AttributeValue::S("uid456".to_string())]))
+    );
+    assert_eq!(result, Some(example));
+    assert_eq!(after_delete, None);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rgb(u8, u8, u8);
+
+impl IntoAttributeValue for Rgb {
+    fn into_attribute_value(self) -> AttributeValue {
+        AttributeValue::S(format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2))
+    }
+}
+
+impl TryFromAttributeValue for Rgb {
+    type Error = String;
+
+    fn try_from_attribute_value(value: &AttributeValue) -> Result<Self, Self::Error> {
+        let hex = value
+            .as_s()
+            .ok()
+            .and_then(|v| v.strip_prefix('#'))
+            .ok_or("expected a hex color".to_string())?;
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|e| e.to_string());
+        Ok(Rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+}
+
+mod ip_as_string {
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use std::net::Ipv4Addr;
+
+    pub fn into_attribute_value(value: Ipv4Addr) -> AttributeValue {
+        AttributeValue::S(value.to_string())
+    }
+
+    pub fn try_from_attribute_value(value: &AttributeValue) -> Result<Ipv4Addr, String> {
+        value
+            .as_s()
+            .map_err(|_| "expected a string".to_string())
+            .and_then(|v| v.parse().map_err(|e: std::net::AddrParseError| e.to_string()))
+    }
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithCustomTypes {
+    #[partition]
+    pub an_id: String,
+    pub color: Rgb,
+    pub other_colors: Vec<Option<Rgb>>,
+    #[dynamo(with = "ip_as_string")]
+    pub ip: Ipv4Addr,
+    #[dynamo(with = "ip_as_string")]
+    pub previous_ips: Option<Vec<Ipv4Addr>>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_custom_types() {
+    let custom_table = "customTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithCustomTypes {
+        an_id: "uid123".to_string(),
+        color: Rgb(255, 0, 16),
+        other_colors: vec![Some(Rgb(0, 0, 0)), None],
+        ip: Ipv4Addr::new(127, 0, 0, 1),
+        previous_ips: Some(vec![Ipv4Addr::new(10, 0, 0, 1)]),
+    };
+
+    init_table(&client, custom_table, "an_id", None).await;
+
+    let db = OrderWithCustomTypesDb::new(client_for_struct, custom_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(custom_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, custom_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(raw_item.get("color"), Some(&AttributeValue::S("#ff0010".to_string())));
+    assert_eq!(raw_item.get("ip"), Some(&AttributeValue::S("127.0.0.1".to_string())));
+    assert_eq!(result, Some(example));
+}
+
+#[test]
+fn should_give_parse_error_when_custom_conversion_fails() {
+    let map = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("color".to_string(), AttributeValue::S("red".to_string())),
+        ("other_colors".to_string(), AttributeValue::L(vec![])),
+        ("ip".to_string(), AttributeValue::S("127.0.0.1".to_string())),
+    ]);
+
+    let result = OrderWithCustomTypes::try_from(map);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Parse error: Could not convert color: expected a hex color"
+    );
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithTimestamps {
+    #[partition]
+    pub an_id: String,
+    #[range]
+    pub created: chrono::DateTime<chrono::Utc>,
+    pub shipped: Option<time::OffsetDateTime>,
+    pub updated: SystemTime,
+    #[dynamo(timestamp = "epoch_seconds")]
+    pub expires: chrono::DateTime<chrono::Utc>,
+    #[dynamo(timestamp = "epoch_millis")]
+    pub reminders: Vec<SystemTime>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct EventWithEpochRange {
+    #[partition]
+    pub an_id: String,
+    #[range]
+    #[dynamo(timestamp = "epoch_millis")]
+    pub at: SystemTime,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_timestamps_with_timestamp_range_key() {
+    let timestamp_table = "timestampTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let created = chrono::DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap();
+    let example = OrderWithTimestamps {
+        an_id: "uid123".to_string(),
+        created,
+        shipped: Some(time::OffsetDateTime::from_unix_timestamp(1_700_000_100).unwrap()),
+        updated: UNIX_EPOCH + Duration::from_nanos(1_700_000_200_000_000_001),
+        expires: chrono::DateTime::from_timestamp(1_800_000_000, 0).unwrap(),
+        reminders: vec![UNIX_EPOCH + Duration::from_millis(1_700_000_300_123)],
+    };
+
+    let db = OrderWithTimestampsDb::new(client_for_struct, timestamp_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(timestamp_table)
+        .key("an_id", AttributeValue::S(example.an_id.clone()))
+        .key("created", AttributeValue::S("2023-11-14T22:13:20.123456789Z".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db.get((example.an_id.clone(), created)).await.expect("To be able to get a result");
+
+    destroy_table(&client, timestamp_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(raw_item.get("shipped"), Some(&AttributeValue::S("2023-11-14T22:15:00.000000000Z".to_string())));
+    assert_eq!(raw_item.get("expires"), Some(&AttributeValue::N("1800000000".to_string())));
+    assert_eq!(
+        raw_item.get("reminders"),
+        Some(&AttributeValue::L(vec![AttributeValue::N("1700000300123".to_string())]))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[tokio::test]
+async fn should_sort_by_epoch_timestamp_range_key() {
+    let epoch_table = "epochTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let later = EventWithEpochRange {
+        an_id: "uid123".to_string(),
+        at: UNIX_EPOCH + Duration::from_millis(1_700_000_000_000),
+    };
+    let earlier = EventWithEpochRange {
+        an_id: "uid123".to_string(),
+        at: UNIX_EPOCH + Duration::from_millis(999_999_999_999),
+    };
+
+    let db = EventWithEpochRangeDb::new(client_for_struct, epoch_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(later.clone()).await.expect("Put to work");
+    db.put(earlier.clone()).await.expect("Put to work");
+
+    let result = db.get_by_partition_key("uid123".to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, epoch_table).await;
+
+    assert_eq!(result, vec![earlier, later]);
+}
+
+#[test]
+fn should_give_parse_error_with_field_name_for_invalid_timestamp() {
+    let map = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("at".to_string(), AttributeValue::S("yesterday".to_string())),
+    ]);
+
+    let result = EventWithEpochRange::try_from(map);
+
+    assert_eq!(result.unwrap_err().to_string(), "Parse error: Could not convert at: expected a number");
+}
+
+#[test]
+fn should_read_back_timestamps_at_the_year_boundaries() {
+    let date_time = |year: i32, month: u32, day: u32, hour: u32, nanos: u32| {
+        chrono::NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|date| date.and_hms_nano_opt(hour, 59, 59, nanos))
+            .unwrap()
+            .and_utc()
+    };
+    let cases = [
+        (date_time(-1, 12, 31, 23, 999_999_999), "-000001-12-31T23:59:59.999999999Z"),
+        (date_time(0, 1, 1, 0, 0), "0000-01-01T00:59:59.000000000Z"),
+        (date_time(9999, 12, 31, 23, 999_999_999), "9999-12-31T23:59:59.999999999Z"),
+        (date_time(10000, 1, 1, 0, 0), "+010000-01-01T00:59:59.000000000Z"),
+    ];
+
+    for (timestamp, expected) in cases {
+        let value = timestamp.into_attribute_value();
+        let result = chrono::DateTime::<chrono::Utc>::try_from_attribute_value(&value);
+
+        assert_eq!(value, AttributeValue::S(expected.to_string()));
+        assert_eq!(result, Ok(timestamp));
+    }
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct ConnectionWithStringLikeTypes {
+    #[partition]
+    #[dynamo(as_string)]
+    pub ip: IpAddr,
+    #[dynamo(as_string)]
+    pub address: SocketAddr,
+    #[dynamo(as_string)]
+    pub previous_ips: Option<Vec<IpAddr>>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_types_as_strings() {
+    let as_string_table = "asStringTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = ConnectionWithStringLikeTypes {
+        ip: "10.0.0.1".parse().unwrap(),
+        address: "127.0.0.1:8080".parse().unwrap(),
+        previous_ips: Some(vec!["::1".parse().unwrap()]),
+    };
+
+    let db = ConnectionWithStringLikeTypesDb::new(client_for_struct, as_string_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(as_string_table)
+        .key("ip", AttributeValue::S("10.0.0.1".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db.get(example.ip).await.expect("To be able to get a result");
+
+    destroy_table(&client, as_string_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(raw_item.get("address"), Some(&AttributeValue::S("127.0.0.1:8080".to_string())));
+    assert_eq!(
+        raw_item.get("previous_ips"),
+        Some(&AttributeValue::L(vec![AttributeValue::S("::1".to_string())]))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[test]
+fn should_give_parse_error_with_from_str_message_for_invalid_string() {
+    let map = HashMap::from([
+        ("ip".to_string(), AttributeValue::S("10.0.0.1".to_string())),
+        ("address".to_string(), AttributeValue::S("not an address".to_string())),
+    ]);
+
+    let result = ConnectionWithStringLikeTypes::try_from(map);
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Parse error: Could not convert address: invalid socket address syntax"
+    );
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Shipment {
+    pub carrier: String,
+    pub weight: f64,
+    pub insured: bool,
+    pub tracking_codes: Vec<Option<u32>>,
+    pub dimensions: HashMap<String, u16>,
+    pub kind: ShipmentKind,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ShipmentKind {
+    Standard,
+    Express { hours: u8 },
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithSerdeFields {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(serde)]
+    pub shipment: Shipment,
+    #[dynamo(serde)]
+    pub returns: Vec<Shipment>,
+    #[dynamo(serde)]
+    pub previous_shipment: Option<Shipment>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_serde_fields_as_documents() {
+    let serde_table = "serdeTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let shipment = Shipment {
+        carrier: "PostNL".to_string(),
+        weight: 1.5,
+        insured: true,
+        tracking_codes: vec![Some(1), None],
+        dimensions: HashMap::from([("width".to_string(), 20)]),
+        kind: ShipmentKind::Express { hours: 24 },
+    };
+    let example = OrderWithSerdeFields {
+        an_id: "uid123".to_string(),
+        shipment: shipment.clone(),
+        returns: vec![Shipment {
+            kind: ShipmentKind::Standard,
+            ..shipment
+        }],
+        previous_shipment: None,
+    };
+
+    init_table(&client, serde_table, "an_id", None).await;
+
+    let db = OrderWithSerdeFieldsDb::new(client_for_struct, serde_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(serde_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, serde_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    let raw_shipment = raw_item.get("shipment").and_then(|v| v.as_m().ok()).expect("Shipment to be a map");
+    assert_eq!(raw_shipment.get("weight"), Some(&AttributeValue::N("1.5".to_string())));
+    assert_eq!(raw_shipment.get("insured"), Some(&AttributeValue::Bool(true)));
+    assert_eq!(
+        raw_shipment.get("tracking_codes"),
+        Some(&AttributeValue::L(vec![AttributeValue::N("1".to_string()), AttributeValue::Null(true)]))
+    );
+    assert!(matches!(raw_item.get("returns"), Some(AttributeValue::L(_))));
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithSerdeCollections {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(serde)]
+    pub weights_per_box: HashMap<u32, f64>,
+    #[dynamo(serde)]
+    pub carriers: HashSet<String>,
+    #[dynamo(serde)]
+    pub zones: BTreeSet<u8>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_serde_collections_as_a_single_document() {
+    let serde_collections_table = "serdeCollectionsTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithSerdeCollections {
+        an_id: "uid123".to_string(),
+        weights_per_box: HashMap::from([(1, 1.5), (2, 0.25)]),
+        carriers: HashSet::from(["DHL".to_string()]),
+        zones: BTreeSet::from([3, 1]),
+    };
+
+    init_table(&client, serde_collections_table, "an_id", None).await;
+
+    let db = OrderWithSerdeCollectionsDb::new(client_for_struct, serde_collections_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(serde_collections_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, serde_collections_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    let raw_weights = raw_item.get("weights_per_box").and_then(|v| v.as_m().ok()).expect("Weights to be a map");
+    assert_eq!(raw_weights.get("2"), Some(&AttributeValue::N("0.25".to_string())));
+    assert_eq!(raw_item.get("carriers"), Some(&AttributeValue::L(vec![AttributeValue::S("DHL".to_string())])));
+    assert_eq!(
+        raw_item.get("zones"),
+        Some(&AttributeValue::L(vec![AttributeValue::N("1".to_string()), AttributeValue::N("3".to_string())]))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithUnserializableSerdeField {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(serde)]
+    pub routes: HashMap<(u8, u8), String>,
+}
+
+#[tokio::test]
+async fn should_return_validation_error_when_serde_cannot_convert_a_field() {
+    let unserializable_table = "unserializableSerdeTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = OrderWithUnserializableSerdeField {
+        an_id: "uid123".to_string(),
+        routes: HashMap::from([((1, 2), "north".to_string())]),
+    };
+
+    init_table(&client, unserializable_table, "an_id", None).await;
+
+    let db = OrderWithUnserializableSerdeFieldDb::new(client_for_struct, unserializable_table);
+
+    let put_result = db.put(example.clone()).await;
+    let batch_put_result = db.batch_put(vec![example.clone()]).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, unserializable_table).await;
+
+    assert!(matches!(
+        put_result,
+        Err(OrderWithUnserializableSerdeFieldDbPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "routes"
+    ));
+    assert!(matches!(
+        batch_put_result,
+        Err(OrderWithUnserializableSerdeFieldDbBatchPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "routes"
+    ));
+    assert_eq!(result, None);
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct WebhookWithUnserializableJsonField {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(json)]
+    pub retries_per_route: HashMap<(u8, u8), u8>,
+}
+
+#[tokio::test]
+async fn should_return_validation_error_when_a_json_field_cannot_be_serialized() {
+    let unserializable_json_table = "unserializableJsonTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = WebhookWithUnserializableJsonField {
+        an_id: "uid123".to_string(),
+        retries_per_route: HashMap::from([((1, 2), 3)]),
+    };
+
+    init_table(&client, unserializable_json_table, "an_id", None).await;
+
+    let db = WebhookWithUnserializableJsonFieldDb::new(client_for_struct, unserializable_json_table);
+
+    let put_result = db.put(example.clone()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, unserializable_json_table).await;
+
+    assert!(matches!(
+        put_result,
+        Err(WebhookWithUnserializableJsonFieldDbPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "retries_per_route"
+    ));
+    assert_eq!(result, None);
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct WebhookWithJsonFields {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(json)]
+    pub body: serde_json::Value,
+    #[dynamo(json)]
+    pub shipment: Option<Shipment>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct WebhookWithJsonList {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(json)]
+    pub attempts: Vec<Shipment>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_json_fields() {
+    let json_table = "jsonTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = WebhookWithJsonFields {
+        an_id: "uid123".to_string(),
+        body: serde_json::json!({"event": "paid", "amount": 12.5, "tags": ["a", null]}),
+        shipment: Some(Shipment {
+            carrier: "DHL".to_string(),
+            weight: 2.0,
+            insured: false,
+            tracking_codes: vec![],
+            dimensions: HashMap::new(),
+            kind: ShipmentKind::Standard,
+        }),
+    };
+
+    init_table(&client, json_table, "an_id", None).await;
+
+    let db = WebhookWithJsonFieldsDb::new(client_for_struct, json_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(json_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, json_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(
+        raw_item.get("body"),
+        Some(&AttributeValue::S(r#"{"amount":12.5,"event":"paid","tags":["a",null]}"#.to_string()))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[tokio::test]
+async fn should_save_a_json_list_as_a_single_string() {
+    let json_list_table = "jsonListTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = WebhookWithJsonList {
+        an_id: "uid123".to_string(),
+        attempts: vec![Shipment {
+            carrier: "DHL".to_string(),
+            weight: 2.0,
+            insured: false,
+            tracking_codes: vec![Some(7)],
+            dimensions: HashMap::new(),
+            kind: ShipmentKind::Standard,
+        }],
+    };
+
+    init_table(&client, json_list_table, "an_id", None).await;
+
+    let db = WebhookWithJsonListDb::new(client_for_struct, json_list_table);
+
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(json_list_table, &client, example.an_id.as_str()).await;
+    let result = db.get(example.an_id.to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, json_list_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(
+        raw_item.get("attempts"),
+        Some(&AttributeValue::S(
+            r#"[{"carrier":"DHL","weight":2.0,"insured":false,"tracking_codes":[7],"dimensions":{},"kind":"Standard"}]"#.to_string()
+        ))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[test]
+fn should_give_parse_error_with_field_name_for_invalid_json() {
+    let map = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("body".to_string(), AttributeValue::S("{\"event\":".to_string())),
+    ]);
+
+    let result = WebhookWithJsonFields::try_from(map);
+
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .starts_with("Parse error: Could not convert body: EOF while parsing"));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct PaymentWithDecimals {
+    #[partition]
+    pub an_id: String,
+    #[range]
+    pub amount: Decimal,
+    pub fee: Option<Decimal>,
+    pub exchange_rate: BigDecimal,
+    pub history: Vec<Decimal>,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_decimals_without_losing_precision() {
+    let decimal_table = "decimalTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let example = PaymentWithDecimals {
+        an_id: "uid123".to_string(),
+        amount: Decimal::from_str("19.99").unwrap(),
+        fee: Some(Decimal::from_str("0.10").unwrap()),
+        exchange_rate: BigDecimal::from_str("1.0832749999999999999999999999").unwrap(),
+        history: vec![Decimal::from_str("-5.01").unwrap()],
+    };
+
+    let db = PaymentWithDecimalsDb::new(client_for_struct, decimal_table);
+
+    db.create_table().await.expect("Create table to work");
+    db.put(example.clone()).await.expect("Put to work");
+
+    let raw = client
+        .get_item()
+        .table_name(decimal_table)
+        .key("an_id", AttributeValue::S(example.an_id.clone()))
+        .key("amount", AttributeValue::N("19.99".to_string()))
+        .send()
+        .await
+        .expect("To be able to get a result");
+    let result = db
+        .get((example.an_id.clone(), example.amount))
+        .await
+        .expect("To be able to get a result");
+
+    destroy_table(&client, decimal_table).await;
+
+    let raw_item = raw.item().expect("Item to exist");
+    assert_eq!(
+        raw_item.get("exchange_rate"),
+        Some(&AttributeValue::N("1.0832749999999999999999999999".to_string()))
+    );
+    assert_eq!(result, Some(example));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct MeasurementWithInvalidNumbers {
+    #[partition]
+    pub an_id: String,
+    pub value: f64,
+    pub counters: HashMap<String, Vec<u128>>,
+    pub precise: Option<BigDecimal>,
+}
+
+#[tokio::test]
+async fn should_return_validation_error_for_numbers_dynamodb_does_not_accept() {
+    let invalid_numbers_table = "invalidNumbersTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let valid = MeasurementWithInvalidNumbers {
+        an_id: "uid123".to_string(),
+        value: 1.5,
+        counters: HashMap::from([("hits".to_string(), vec![10_u128.pow(37)])]),
+        precise: None,
+    };
+
+    init_table(&client, invalid_numbers_table, "an_id", None).await;
+
+    let db = MeasurementWithInvalidNumbersDb::new(client_for_struct, invalid_numbers_table);
+
+    let valid_result = db.put(valid.clone()).await;
+    let nan_result = db
+        .put(MeasurementWithInvalidNumbers {
+            value: f64::NAN,
+            ..valid.clone()
+        })
+        .await;
+    let too_many_digits_result = db
+        .batch_put(vec![MeasurementWithInvalidNumbers {
+            counters: HashMap::from([("hits".to_string(), vec![1, u128::MAX])]),
+            ..valid.clone()
+        }])
+        .await;
+    let too_precise_result = db
+        .put(MeasurementWithInvalidNumbers {
+            precise: Some(BigDecimal::from_str("0.123456789012345678901234567890123456789").unwrap()),
+            ..valid.clone()
+        })
+        .await;
+
+    destroy_table(&client, invalid_numbers_table).await;
+
+    assert!(valid_result.is_ok());
+    assert!(matches!(
+        nan_result,
+        Err(MeasurementWithInvalidNumbersDbPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "value"
+    ));
+    assert!(matches!(
+        too_many_digits_result,
+        Err(MeasurementWithInvalidNumbersDbBatchPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "counters.hits[1]"
+    ));
+    assert!(matches!(
+        too_precise_result,
+        Err(MeasurementWithInvalidNumbersDbPutError::ValidationError(ValidationError { attribute, .. })) if attribute == "precise"
+    ));
+}
+
+#[test]
+fn should_only_accept_numbers_within_dynamodb_precision_and_range() {
+    for valid in ["0", "-0.000", "12.5", "-12.5e3", "1E+125", "1e-130", "12345678901234567890123456789012345678000"] {
+        assert_eq!(dynamodb_helper::validate_number(valid), Ok(()), "{valid} should be valid");
+    }
+    for invalid in ["NaN", "inf", "-inf", "", "1.2.3", "1e126", "1e-131", "123456789012345678901234567890123456789"] {
+        assert!(dynamodb_helper::validate_number(invalid).is_err(), "{invalid} should be invalid");
+    }
+    assert!(dynamodb_helper::validate_number(&f64::MAX.to_string()).is_err());
+    assert!(dynamodb_helper::validate_number(&f64::MIN_POSITIVE.to_string()).is_err());
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithOptionals {
+    #[partition]
+    pub an_id: String,
+    pub comment: Option<String>,
+    pub amount: Option<u32>,
+    pub items: Option<Vec<LineItem>>,
+    pub labels: Option<HashMap<String, String>>,
+    pub address: Option<Address>,
+    pub status: Option<OrderStatus>,
+    #[dynamo(store_null)]
+    pub tracking_code: Option<String>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(store_null)]
+pub struct OrderStoringNulls {
+    #[partition]
+    pub an_id: String,
+    pub comment: Option<String>,
+    pub tags: Option<HashSet<String>>,
+}
+
+#[tokio::test]
+async fn should_read_nulls_as_none_and_store_null_when_asked() {
+    let null_table = "nullTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let null_item = HashMap::from([
+        ("an_id".to_string(), AttributeValue::S("uid123".to_string())),
+        ("comment".to_string(), AttributeValue::Null(true)),
+        ("amount".to_string(), AttributeValue::Null(true)),
+        ("items".to_string(), AttributeValue::Null(true)),
+        ("labels".to_string(), AttributeValue::Null(true)),
+        ("address".to_string(), AttributeValue::Null(true)),
+        ("status".to_string(), AttributeValue::Null(true)),
+        ("tracking_code".to_string(), AttributeValue::Null(true)),
+    ]);
+
+    init_table(&client, null_table, "an_id", None).await;
+    put_hashmap(null_table, &client, null_item).await;
+
+    let db = OrderWithOptionalsDb::new(client_for_struct, null_table);
+    let read_result = db.get("uid123".to_string()).await.expect("To be able to get a result");
+
+    let without_values = OrderWithOptionals {
+        an_id: "uid456".to_string(),
+        comment: None,
+        amount: None,
+        items: None,
+        labels: None,
+        address: None,
+        status: None,
+        tracking_code: None,
+    };
+    db.put(without_values.clone()).await.expect("Put to work");
+    let raw_without_values = get_order_struct(null_table, &client, "uid456").await;
+
+    let struct_db = OrderStoringNullsDb::new(create_client().await, null_table);
+    struct_db
+        .put(OrderStoringNulls {
+            an_id: "uid789".to_string(),
+            comment: None,
+            tags: None,
+        })
+        .await
+        .expect("Put to work");
+    let raw_storing_nulls = get_order_struct(null_table, &client, "uid789").await;
+
+    destroy_table(&client, null_table).await;
+
+    assert_eq!(
+        read_result,
+        Some(OrderWithOptionals {
+            an_id: "uid123".to_string(),
+            ..without_values
+        })
+    );
+    let raw_item = raw_without_values.item().expect("Item to exist");
+    assert!(raw_item.get("comment").is_none());
+    assert_eq!(raw_item.get("tracking_code"), Some(&AttributeValue::Null(true)));
+    let raw_item = raw_storing_nulls.item().expect("Item to exist");
+    assert_eq!(raw_item.get("comment"), Some(&AttributeValue::Null(true)));
+    assert_eq!(raw_item.get("tags"), Some(&AttributeValue::Null(true)));
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithDefaultPriority {
+    #[partition]
+    pub an_id: String,
+    #[dynamo(store_null, default = "default_priority")]
+    pub priority: Option<u8>,
+}
+
+fn default_priority() -> Option<u8> {
+    Some(5)
+}
+
+#[tokio::test]
+async fn should_only_use_the_default_of_an_optional_field_when_the_attribute_is_missing() {
+    let null_default_table = "nullDefaultTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let without_priority = OrderWithDefaultPriority {
+        an_id: "uid123".to_string(),
+        priority: None,
+    };
+
+    init_table(&client, null_default_table, "an_id", None).await;
+    put_hashmap(
+        null_default_table,
+        &client,
+        HashMap::from([("an_id".to_string(), AttributeValue::S("uid456".to_string()))]),
+    )
+    .await;
+
+    let db = OrderWithDefaultPriorityDb::new(client_for_struct, null_default_table);
+    db.put(without_priority.clone()).await.expect("Put to work");
+
+    let raw = get_order_struct(null_default_table, &client, "uid123").await;
+    let null_result = db.get("uid123".to_string()).await.expect("To be able to get a result");
+    let missing_result = db.get("uid456".to_string()).await.expect("To be able to get a result");
+
+    destroy_table(&client, null_default_table).await;
+
+    assert_eq!(raw.item().and_then(|i| i.get("priority")), Some(&AttributeValue::Null(true)));
+    assert_eq!(null_result, Some(without_priority));
+    assert_eq!(
+        missing_result,
+        Some(OrderWithDefaultPriority {
+            an_id: "uid456".to_string(),
+            priority: Some(5),
+        })
+    );
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested)]
+pub struct AuditFields {
+    pub created_by: String,
+    pub created_at: u64,
+    pub updated_at: Option<u64>,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+#[dynamo(nested)]
+pub struct Tracked {
+    pub version: u32,
+    #[dynamo(flatten)]
+    pub audit: AuditFields,
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithFlattened {
+    #[partition]
+    pub an_id: String,
+    pub address: Address,
+    #[dynamo(flatten)]
+    pub tracked: Tracked,
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_flattened_structs() {
+    let flatten_table = "flattenTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let order = OrderWithFlattened {
+        an_id: "uid123".to_string(),
+        address: Address {
+            street: "Main Street".to_string(),
+            number: 5,
+            box_number: None,
+        },
+        tracked: Tracked {
+            version: 2,
+            audit: AuditFields {
+                created_by: "someone".to_string(),
+                created_at: 1700000000,
+                updated_at: None,
+            },
+        },
+    };
+
+    init_table(&client, flatten_table, "an_id", None).await;
+
+    let db = OrderWithFlattenedDb::new(client_for_struct, flatten_table);
+    db.put(order.clone()).await.expect("Put to work");
+
+    let raw_result = get_order_struct(flatten_table, &client, "uid123").await;
+    let result = db.get("uid123".to_string()).await.expect("Get to work");
+
+    destroy_table(&client, flatten_table).await;
+
+    let raw_item = raw_result.item().expect("Item to exist");
+    assert_eq!(raw_item.get("created_by"), Some(&AttributeValue::S("someone".to_string())));
+    assert_eq!(raw_item.get("version"), Some(&AttributeValue::N("2".to_string())));
+    assert!(raw_item.get("tracked").is_none());
+    assert_eq!(result, Some(order));
+    assert_eq!(
+        <OrderWithFlattened as dynamodb_helper::Flatten>::ATTRIBUTE_NAMES,
+        &["an_id", "address", "version", "created_by", "created_at", "updated_at"]
+    );
+}
+
+type CustomerId = String;
+type AmountInCents = u64;
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithQualifiedTypes {
+    #[partition]
+    #[dynamo(type = "string")]
+    pub an_id: CustomerId,
+    #[range]
+    pub initial: char,
+    pub name: std::string::String,
+    pub comment: core::option::Option<String>,
+    pub numbers: alloc_like::Vec<i32>,
+    pub labels: std::collections::HashMap<std::string::String, u8>,
+    pub tags: std::collections::HashSet<String>,
+    #[dynamo(type = "number")]
+    pub total: AmountInCents,
+    #[dynamo(type = "number")]
+    pub discounts: Vec<AmountInCents>,
+    pub address: Box<Address>,
+    pub shared_items: Arc<Vec<LineItem>>,
+    pub previous: Option<Box<Address>>,
+    pub origin: Cow<'static, str>,
+    pub grades: Vec<char>,
+}
+
+// stands in for `alloc::vec`, which needs `extern crate alloc`
+mod alloc_like {
+    pub type Vec<T> = std::vec::Vec<T>;
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_qualified_and_wrapped_types() {
+    let wrapped_table = "wrappedTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let order = OrderWithQualifiedTypes {
+        an_id: "uid123".to_string(),
+        initial: 'Q',
+        name: "an order".to_string(),
+        comment: Some("a comment".to_string()),
+        numbers: vec![1, 2],
+        labels: HashMap::from([("priority".to_string(), 1)]),
+        tags: HashSet::from(["urgent".to_string()]),
+        total: 1250,
+        discounts: vec![100, 50],
+        address: Box::new(Address {
+            street: "Main Street".to_string(),
+            number: 5,
+            box_number: None,
+        }),
+        shared_items: Arc::new(vec![LineItem {
+            product: "book".to_string(),
+            amount: 2,
+        }]),
+        previous: None,
+        origin: Cow::Borrowed("webshop"),
+        grades: vec!['A', 'b'],
+    };
+
+    let db = OrderWithQualifiedTypesDb::new(client_for_struct, wrapped_table);
+    db.create_table().await.expect("Create table to work");
+    db.put(order.clone()).await.expect("Put to work");
+
+    let description = client
+        .describe_table()
+        .table_name(wrapped_table)
+        .send()
+        .await
+        .expect("To be able to describe tables");
+    let result = db.get(("uid123".to_string(), 'Q')).await.expect("Get to work");
+
+    destroy_table(&client, wrapped_table).await;
+
+    let definitions = description.table.unwrap().attribute_definitions.unwrap();
+    assert!(definitions.iter().all(|d| d.attribute_type == aws_sdk_dynamodb::types::ScalarAttributeType::S));
+    assert_eq!(result, Some(order));
+    assert_eq!(
+        char::try_from_attribute_value(&AttributeValue::S("too long".to_string())),
+        Err("expected a single character, found 'too long'".to_string())
+    );
+}
+
+#[derive(DynamoDb, Debug, Clone, PartialEq)]
+pub struct OrderWithOtherCollections {
+    #[partition]
+    pub an_id: String,
+    pub counts: BTreeMap<String, i32>,
+    pub queue: VecDeque<String>,
+    pub coordinates: [f64; 3],
+    pub optional_names: [Option<String>; 2],
+    pub ordered_labels: IndexMap<String, Vec<u8>>,
+    pub nested: Vec<BTreeMap<String, Address>>,
+    #[dynamo(list)]
+    pub checksum: [u8; 4],
+}
+
+#[tokio::test]
+async fn should_be_able_to_put_and_get_other_collections() {
+    let collections_table = "otherCollectionsTable";
+    let client = create_client().await;
+    let client_for_struct = create_client().await;
+    let order = OrderWithOtherCollections {
+        an_id: "uid123".to_string(),
+        counts: BTreeMap::from([("b".to_string(), 2), ("a".to_string(), 1)]),
+        queue: VecDeque::from(["first".to_string(), "second".to_string()]),
+        coordinates: [1.5, -2.0, 3.25],
+        optional_names: [Some("name".to_string()), None],
+        ordered_labels: IndexMap::from([("z".to_string(), vec![1, 2]), ("a".to_string(), vec![3])]),
+        nested: vec![BTreeMap::from([(
+            "home".to_string(),
+            Address {
+                street: "Main Street".to_string(),
+                number: 5,
+                box_number: None,
+            },
+        )])],
+        checksum: [1, 2, 3, 4],
+    };
+
+    let db = OrderWithOtherCollectionsDb::new(client_for_struct, collections_table);
+    db.create_table().await.expect("Create table to work");
+    db.put(order.clone()).await.expect("Put to work");
+
+    let raw_item = client
+        .get_item()
+        .table_name(collections_table)
+        .key("an_id", AttributeValue::S("uid123".to_string()))
+        .send()
+        .await
+        .expect("Raw get to work")
+        .item
+        .unwrap();
+    let result = db.get("uid123".to_string()).await.expect("Get to work");
+
+    destroy_table(&client, collections_table).await;
+
+    assert_eq!(raw_item.get("coordinates").unwrap().as_l().unwrap().len(), 3);
+    assert_eq!(raw_item.get("checksum").unwrap().as_l().unwrap().len(), 4);
+    assert!(raw_item.get("counts").unwrap().is_m());
+    assert_eq!(result, Some(order));
+}
+
+#[test]
+fn should_give_parse_error_for_array_with_wrong_length() {
+    let order = OrderWithOtherCollections {
+        an_id: "uid123".to_string(),
+        counts: BTreeMap::new(),
+        queue: VecDeque::new(),
+        coordinates: [0.0, 0.0, 0.0],
+        optional_names: [None, None],
+        ordered_labels: IndexMap::new(),
+        nested: vec![],
+        checksum: [0; 4],
+    };
+    let mut map = HashMap::try_from(order).expect("Conversion to work");
+    map.insert(
+        "coordinates".to_string(),
+        AttributeValue::L(vec![AttributeValue::N("1".to_string()), AttributeValue::N("2".to_string())]),
+    );
+
+    let result = OrderWithOtherCollections::try_from(map);
+
+    assert_eq!(result.unwrap_err().to_string(), "Parse error: Expected coordinates to have 3 elements, found 2");
+}
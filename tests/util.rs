@@ -1,4 +1,4 @@
-use aws_sdk_dynamodb::config::{Credentials, Region};
+use aws_sdk_dynamodb::config::{BehaviorVersion, Credentials, Region};
 use aws_sdk_dynamodb::operation::get_item::GetItemOutput;
 use aws_sdk_dynamodb::types::{AttributeDefinition, AttributeValue, BillingMode, KeySchemaElement, KeyType, ScalarAttributeType};
 use aws_sdk_dynamodb::Client;
@@ -51,7 +51,7 @@ pub fn create_order_struct_with_range() -> OrderStructWithRange {
 }
 
 pub async fn create_client() -> Client {
-    let config = aws_config::from_env()
+    let config = aws_config::defaults(BehaviorVersion::latest())
         .region(Region::new("eu-central-1"))
         .credentials_provider(Credentials::new("accesskey", "privatekey", None, None, "dummy"))
         .load()
@@ -61,8 +61,7 @@ pub async fn create_client() -> Client {
         .region(Some(Region::from_static("eu-west-1")))
         .endpoint_url("http://localhost:8000")
         .build();
-    let client = Client::from_conf(dynamodb_local_config);
-    client
+    Client::from_conf(dynamodb_local_config)
 }
 
 pub async fn init_table(client: &Client, table_name: &str, partition_key: &str, range_key_option: Option<&str>) {
@@ -139,11 +138,8 @@ pub async fn put_order_struct(table: &str, client: &Client, struc: &OrderStruct)
             AttributeValue::L(struc.numbers.iter().map(|v| AttributeValue::N(v.to_string())).collect()),
         ),
     ]);
-    if struc.something_optional.is_some() {
-        basic_map.insert(
-            "something_optional".to_string(),
-            AttributeValue::S(struc.something_optional.as_ref().unwrap().to_string()),
-        );
+    if let Some(something_optional) = &struc.something_optional {
+        basic_map.insert("something_optional".to_string(), AttributeValue::S(something_optional.to_string()));
     };
     put_hashmap(table, client, basic_map).await;
 }
@@ -156,7 +152,7 @@ pub async fn put_order_with_range_struct(table: &str, client: &Client, example:
         ("total_amount".to_string(), AttributeValue::N(example.total_amount.to_string())),
         (
             "names".to_string(),
-            AttributeValue::L(example.names.iter().map(|v| v.clone()).map(|v| AttributeValue::S(v)).collect()),
+            AttributeValue::L(example.names.iter().cloned().map(AttributeValue::S).collect()),
         ),
         (
             "map_values".to_string(),